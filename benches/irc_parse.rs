@@ -0,0 +1,15 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use twitch_rs::chat::*;
+
+const PRIVMSG: &str = "@badge-info=subscriber/8;badges=subscriber/6,premium/1;bits=100;client-nonce=bbf5ac0a9a0a5f9d5a1c1e3b8d0f1f3a;color=#1E90FF;display-name=Some\\sUser;emotes=25:0-4,12-16/1902:6-10;first-msg=0;flags=;id=b34ccfc7-4977-403a-8a94-33c6bac34fb8;mod=0;returning-chatter=0;room-id=1337;subscriber=1;tmi-sent-ts=1642696567751;turbo=0;user-id=12345678;user-type= :someuser!someuser@someuser.tmi.twitch.tv PRIVMSG #channel :Kappa Keepo Kappa cheer100\r\n";
+const USERNOTICE: &str = "@badge-info=subscriber/1;badges=subscriber/0;color=#0000FF;display-name=Gifter;emotes=;flags=;id=e9176cd8-5e22-4684-ad40-ce53c2561c5e;login=gifter;mod=0;msg-id=subgift;msg-param-gift-months=1;msg-param-months=2;msg-param-origin-id=da\\s39\\sa3;msg-param-recipient-display-name=Recipient;msg-param-recipient-id=87654321;msg-param-recipient-user-name=recipient;msg-param-sender-count=5;msg-param-sub-plan-name=Channel\\sSubscription;msg-param-sub-plan=1000;room-id=1337;subscriber=1;system-msg=Gifter\\sgifted\\sa\\sTier\\s1\\ssub\\sto\\sRecipient!;tmi-sent-ts=1642696567751;user-id=12345678;user-type= :tmi.twitch.tv USERNOTICE #channel\r\n";
+
+fn parse(c: &mut Criterion) {
+    c.bench_function("parse privmsg", |b| b.iter(|| IrcMessage::parse(black_box(PRIVMSG))));
+    c.bench_function("privmsg event", |b| b.iter(|| TwitchEvent::from_irc(IrcMessage::parse(black_box(PRIVMSG)).unwrap())));
+    c.bench_function("usernotice event", |b| b.iter(|| TwitchEvent::from_irc(IrcMessage::parse(black_box(USERNOTICE)).unwrap())));
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+use std::error::Error;
+
+use futures::StreamExt;
+use twitch_rs::chat::*;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let mut chat = ChatClient::connect_anonymous(ChatConfig::new()).await?;
+
+    for channel in std::env::args().skip(1) {
+        chat.join(channel).await?;
+    }
+
+    let mut events = chat.events();
+    while let Some(event) = events.next().await {
+        match event {
+            ChatEvent::Message(line) => {
+                if let Ok(TwitchEvent::PrivMsg(message)) = line.event() {
+                    println!("#{} <{}> {}", message.channel, message.display_name.unwrap_or_default(), message.text);
+                }
+            },
+            ChatEvent::Disconnected(reason) => {
+                println!("Disconnected: {:?}", reason);
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,40 +1,21 @@
 use std::error::Error;
 
 use serde::Serialize;
-use reqwest::ClientBuilder;
 
 use twitch_rs::{TwitchClient, api::helix::{pagination, clips::*, search::*}};
 
 #[derive(Debug, Serialize)]
 struct Clip {
-    download_url: String,
+    download_url: Option<String>,
 
     #[serde(flatten)]
     clip_info: GetClipsResponse
 }
 
-impl Clip {
-    #[allow(dead_code)]
-    async fn get_size(&self) -> Result<Option<u64>, Box<dyn Error>> {
-        Ok(
-            ClientBuilder::new().build()?
-                .head(&self.download_url)
-                .send()
-                .await?
-                .headers()
-                .get("content-length")
-                .map(|x| x.to_str())
-                .transpose()?
-                .map(|x| x.parse::<u64>())
-                .transpose()?
-        )
-    }
-}
-
 impl From<GetClipsResponse> for Clip {
     fn from(x: GetClipsResponse) -> Self {
         Self {
-            download_url: format!("{}.mp4", &x.thumbnail_url.rsplitn(3, '-').nth(2).unwrap()),
+            download_url: clip_media_url(&x).ok(),
             clip_info: x
         }
     }
@@ -56,7 +37,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
             .with_query(&requested_channel)
     ).await?.data;
 
-    let channel: &ChannelResponse = channel_list.get(0).expect("Cannot find channel.");
+    let channel: &ChannelResponse = channel_list.first().expect("Cannot find channel.");
 
     let clips_list: Vec<Clip> = pagination::get_all(|page| {
         client.clips.get_clips(
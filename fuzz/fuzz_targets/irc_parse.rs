@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use twitch_rs::chat::*;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(line) = std::str::from_utf8(data) {
+        if let Ok(message) = IrcMessage::parse(line) {
+            let _ = message.params().count();
+            for (key, _) in message.tags().iter() {
+                let _ = message.tag(key);
+            }
+            let _ = TwitchEvent::from_irc(message);
+        }
+    }
+});
@@ -0,0 +1,53 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
+use super::AdsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetAdScheduleRequest {
+    broadcaster_id: BroadcasterId
+}
+
+impl GetAdScheduleRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+}
+
+/// Timestamps are empty when the channel is offline or has no scheduled ads.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AdScheduleResponse {
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub next_ad_at: Option<Timestamp>,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub last_ad_at: Option<Timestamp>,
+    /// Length of the next ad break in seconds.
+    pub duration: u32,
+    /// Seconds of pre-roll free time left.
+    pub preroll_free_time: u32,
+    pub snooze_count: u32,
+    pub snooze_refresh_at: Timestamp,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetAdSchedule {
+    async fn get_ad_schedule(&self, request: GetAdScheduleRequest) -> Result<ApiResult<AdScheduleResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetAdSchedule for AdsGroup {
+    async fn get_ad_schedule(&self, request: GetAdScheduleRequest) -> Result<ApiResult<AdScheduleResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:ads")]), "channels/ads", &request).await?)
+    }
+}
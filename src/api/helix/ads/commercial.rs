@@ -0,0 +1,56 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use super::AdsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct StartCommercialRequest {
+    broadcaster_id: BroadcasterId,
+    length: u32
+}
+
+impl StartCommercialRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// Length in seconds, Twitch rounds it to 30, 60, 90, 120, 150 or 180.
+    pub fn with_length(mut self, length: u32) -> Self {
+        self.length = length;
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StartCommercialResponse {
+    pub length: u32,
+    pub message: String,
+    /// Seconds until the next commercial can be started.
+    pub retry_after: u32,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait StartCommercial {
+    async fn start_commercial(&self, request: StartCommercialRequest) -> Result<ApiResult<StartCommercialResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl StartCommercial for AdsGroup {
+    async fn start_commercial(&self, request: StartCommercialRequest) -> Result<ApiResult<StartCommercialResponse>, Box<dyn Error>> {
+        if !(1..=180).contains(&request.length) {
+            return Err(String::from("Commercial length must be between 1 and 180 seconds!").into());
+        }
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("channel:edit:commercial")]), "channels/commercial", &(), &request).await?)
+    }
+}
@@ -0,0 +1,24 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod commercial;
+pub use commercial::*;
+
+mod ad_schedule;
+pub use ad_schedule::*;
+
+mod snooze;
+pub use snooze::*;
+
+pub struct AdsGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl AdsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
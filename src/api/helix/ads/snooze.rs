@@ -0,0 +1,46 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
+use super::AdsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct SnoozeNextAdRequest {
+    broadcaster_id: BroadcasterId
+}
+
+impl SnoozeNextAdRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SnoozeNextAdResponse {
+    pub snooze_count: u32,
+    pub snooze_refresh_at: Timestamp,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub next_ad_at: Option<Timestamp>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait SnoozeNextAd {
+    async fn snooze_next_ad(&self, request: SnoozeNextAdRequest) -> Result<ApiResult<SnoozeNextAdResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl SnoozeNextAd for AdsGroup {
+    async fn snooze_next_ad(&self, request: SnoozeNextAdRequest) -> Result<ApiResult<SnoozeNextAdResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("channel:manage:ads")]), "channels/ads/schedule/snooze", &request).await?)
+    }
+}
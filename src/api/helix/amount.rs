@@ -0,0 +1,121 @@
+
+use std::fmt;
+use serde::{Serialize, Deserialize};
+
+/// A monetary amount stored as an integer in its smallest unit, `value: 550` with
+/// `decimal_places: 2` is `5.50`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Amount {
+    pub value: i64,
+    pub decimal_places: u32,
+    /// ISO-4217 currency code, for example `USD`.
+    pub currency: String
+}
+
+impl Amount {
+    /// Exact decimal representation without the currency, for example `5.50`.
+    pub fn to_decimal_string(&self) -> String {
+        let digits = self.value.unsigned_abs().to_string();
+        let places = self.decimal_places as usize;
+        let sign = if self.value < 0 { "-" } else { "" };
+
+        if places == 0 {
+            return format!("{}{}", sign, digits);
+        }
+
+        let digits = format!("{:0>width$}", digits, width = places + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - places);
+        format!("{}{}.{}", sign, integer, fraction)
+    }
+
+    /// Lossy conversion for display or rough arithmetic, use `rescale` for exact math.
+    pub fn as_f64(&self) -> f64 {
+        self.value as f64 / 10f64.powi(self.decimal_places as i32)
+    }
+
+    /// Returns the same amount expressed with `decimal_places`, or `None` if it would lose precision or overflow.
+    pub fn rescale(&self, decimal_places: u32) -> Option<Self> {
+        let value = if decimal_places >= self.decimal_places {
+            self.value.checked_mul(10i64.checked_pow(decimal_places - self.decimal_places)?)?
+        } else {
+            let divisor = 10i64.checked_pow(self.decimal_places - decimal_places)?;
+            if self.value % divisor != 0 {
+                return None;
+            }
+            self.value / divisor
+        };
+
+        Some(Self {
+            value,
+            decimal_places,
+            currency: self.currency.clone()
+        })
+    }
+
+    /// Adds two amounts of the same currency, returning `None` on a currency mismatch or overflow.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        if self.currency != other.currency {
+            return None;
+        }
+
+        let decimal_places = self.decimal_places.max(other.decimal_places);
+        let lhs = self.rescale(decimal_places)?;
+        let rhs = other.rescale(decimal_places)?;
+
+        Some(Self {
+            value: lhs.value.checked_add(rhs.value)?,
+            decimal_places,
+            currency: self.currency.clone()
+        })
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.to_decimal_string(), self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Amount;
+
+    fn amount(value: i64, decimal_places: u32, currency: &str) -> Amount {
+        Amount {
+            value,
+            decimal_places,
+            currency: String::from(currency)
+        }
+    }
+
+    #[test]
+    fn decimal_string() {
+        assert_eq!(amount(550, 2, "USD").to_decimal_string(), "5.50");
+        assert_eq!(amount(5, 2, "USD").to_decimal_string(), "0.05");
+        assert_eq!(amount(-5, 2, "USD").to_decimal_string(), "-0.05");
+        assert_eq!(amount(-1234, 1, "USD").to_decimal_string(), "-123.4");
+        assert_eq!(amount(42, 0, "JPY").to_decimal_string(), "42");
+        assert_eq!(amount(-42, 0, "JPY").to_decimal_string(), "-42");
+        assert_eq!(amount(i64::MIN, 0, "USD").to_decimal_string(), "-9223372036854775808");
+        assert_eq!(amount(550, 2, "USD").to_string(), "5.50 USD");
+    }
+
+    #[test]
+    fn rescale() {
+        assert_eq!(amount(550, 2, "USD").rescale(4), Some(amount(55000, 4, "USD")));
+        assert_eq!(amount(550, 2, "USD").rescale(1), Some(amount(55, 1, "USD")));
+        assert_eq!(amount(-550, 2, "USD").rescale(0), None);
+        assert_eq!(amount(555, 2, "USD").rescale(1), None);
+        assert_eq!(amount(i64::MAX, 0, "USD").rescale(1), None);
+        assert_eq!(amount(1, 0, "USD").rescale(30), None);
+    }
+
+    #[test]
+    fn checked_add() {
+        assert_eq!(amount(550, 2, "USD").checked_add(&amount(5, 1, "USD")), Some(amount(600, 2, "USD")));
+        assert_eq!(amount(550, 2, "USD").checked_add(&amount(-600, 2, "USD")), Some(amount(-50, 2, "USD")));
+        assert_eq!(amount(550, 2, "USD").checked_add(&amount(550, 2, "EUR")), None);
+        assert_eq!(amount(i64::MAX, 0, "USD").checked_add(&amount(1, 0, "USD")), None);
+        assert_eq!(amount(i64::MAX, 0, "USD").checked_add(&amount(1, 1, "USD")), None);
+    }
+}
@@ -0,0 +1,78 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}};
+use crate::api::helix::time::Timestamp;
+use super::{AnalyticsGroup, AnalyticsReportType, ReportDateRange};
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetExtensionAnalyticsRequest {
+    extension_id: Option<String>,
+    #[serde(rename = "type")]
+    kind: Option<AnalyticsReportType>,
+    started_at: Option<Timestamp>,
+    ended_at: Option<Timestamp>,
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetExtensionAnalyticsRequest {
+    pub fn with_extension_id<S: Into<String>>(mut self, extension_id: Option<S>) -> Self {
+        self.extension_id = extension_id.map(|x| x.into());
+        self
+    }
+
+    pub fn with_type(mut self, kind: Option<AnalyticsReportType>) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// RFC3339 timestamps, both or neither must be given.
+    pub fn with_date_range<S: Into<Timestamp>, T: Into<Timestamp>>(mut self, started_at: S, ended_at: T) -> Self {
+        self.started_at = Some(started_at.into());
+        self.ended_at = Some(ended_at.into());
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExtensionAnalyticsResponse {
+    pub extension_id: String,
+    /// Pre-signed report URL, see `AnalyticsGroup::download_report`.
+    #[serde(rename = "URL")]
+    pub url: String,
+    #[serde(rename = "type")]
+    pub kind: AnalyticsReportType,
+    pub date_range: ReportDateRange,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetExtensionAnalytics {
+    async fn get_extension_analytics(&self, request: GetExtensionAnalyticsRequest) -> Result<ApiResult<ExtensionAnalyticsResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetExtensionAnalytics for AnalyticsGroup {
+    async fn get_extension_analytics(&self, request: GetExtensionAnalyticsRequest) -> Result<ApiResult<ExtensionAnalyticsResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("analytics:read:extensions")]), "analytics/extensions", &request).await?)
+    }
+}
@@ -0,0 +1,79 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}};
+use crate::api::helix::id::GameId;
+use crate::api::helix::time::Timestamp;
+use super::{AnalyticsGroup, AnalyticsReportType, ReportDateRange};
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetGameAnalyticsRequest {
+    game_id: Option<GameId>,
+    #[serde(rename = "type")]
+    kind: Option<AnalyticsReportType>,
+    started_at: Option<Timestamp>,
+    ended_at: Option<Timestamp>,
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetGameAnalyticsRequest {
+    pub fn with_game_id<I: Into<GameId>>(mut self, game_id: Option<I>) -> Self {
+        self.game_id = game_id.map(|x| x.into());
+        self
+    }
+
+    pub fn with_type(mut self, kind: Option<AnalyticsReportType>) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// RFC3339 timestamps, both or neither must be given.
+    pub fn with_date_range<S: Into<Timestamp>, T: Into<Timestamp>>(mut self, started_at: S, ended_at: T) -> Self {
+        self.started_at = Some(started_at.into());
+        self.ended_at = Some(ended_at.into());
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GameAnalyticsResponse {
+    pub game_id: GameId,
+    /// Pre-signed report URL, see `AnalyticsGroup::download_report`.
+    #[serde(rename = "URL")]
+    pub url: String,
+    #[serde(rename = "type")]
+    pub kind: AnalyticsReportType,
+    pub date_range: ReportDateRange,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetGameAnalytics {
+    async fn get_game_analytics(&self, request: GetGameAnalyticsRequest) -> Result<ApiResult<GameAnalyticsResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetGameAnalytics for AnalyticsGroup {
+    async fn get_game_analytics(&self, request: GetGameAnalyticsRequest) -> Result<ApiResult<GameAnalyticsResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("analytics:read:games")]), "analytics/games", &request).await?)
+    }
+}
@@ -0,0 +1,57 @@
+
+use std::{collections::HashMap, error::Error, rc::Rc};
+use serde::{Serialize, Deserialize, de::DeserializeOwned};
+use serde_json::Value;
+use crate::TwitchClientInner;
+use crate::api::helix::time::Timestamp;
+
+mod extension;
+pub use extension::*;
+
+mod game;
+pub use game::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AnalyticsReportType {
+    OverviewV2,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReportDateRange {
+    pub started_at: Timestamp,
+    pub ended_at: Timestamp,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+pub struct AnalyticsGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl AnalyticsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+
+    /// Downloads the CSV report behind a report `URL` and deserializes each row by its header names.
+    /// Use `HashMap<String, String>` as `T` to keep every column untyped.
+    pub async fn download_report<T: DeserializeOwned, S: Into<String>>(&self, url: S) -> Result<Vec<T>, Box<dyn Error>> {
+        let report = self.client.download(url).await?.bytes().await?;
+
+        let mut reader = csv::Reader::from_reader(report.as_ref());
+        let mut rows = Vec::new();
+        for row in reader.deserialize() {
+            rows.push(row?);
+        }
+
+        Ok(rows)
+    }
+}
@@ -0,0 +1,97 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::Timestamp;
+use super::BitsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetCheermotesRequest {
+    broadcaster_id: Option<BroadcasterId>
+}
+
+impl GetCheermotesRequest {
+    /// Include the broadcaster's custom cheermotes.
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: Option<I>) -> Self {
+        self.broadcaster_id = broadcaster_id.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CheermoteType {
+    GlobalFirstParty,
+    GlobalThirdParty,
+    ChannelCustom,
+    DisplayOnly,
+    Sponsored,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+/// Image URLs keyed by scale, for example `"1"`, `"1.5"` or `"4"`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheermoteFormats {
+    pub animated: HashMap<String, String>,
+    #[serde(rename = "static")]
+    pub static_: HashMap<String, String>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheermoteImages {
+    pub dark: CheermoteFormats,
+    pub light: CheermoteFormats,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheermoteTier {
+    pub min_bits: u64,
+    pub id: String,
+    pub color: String,
+    pub images: CheermoteImages,
+    pub can_cheer: bool,
+    pub show_in_bits_card: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheermoteResponse {
+    pub prefix: String,
+    pub tiers: Vec<CheermoteTier>,
+    #[serde(rename = "type")]
+    pub kind: CheermoteType,
+    pub order: u32,
+    pub last_updated: Timestamp,
+    pub is_charitable: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetCheermotes {
+    async fn get_cheermotes(&self, request: GetCheermotesRequest) -> Result<ApiResult<CheermoteResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetCheermotes for BitsGroup {
+    async fn get_cheermotes(&self, request: GetCheermotesRequest) -> Result<ApiResult<CheermoteResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, "bits/cheermotes", &request).await?)
+    }
+}
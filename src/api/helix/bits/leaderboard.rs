@@ -0,0 +1,102 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::UserId;
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
+use super::BitsGroup;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum LeaderboardPeriod {
+    Day,
+    Week,
+    Month,
+    Year,
+    All
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetBitsLeaderboardRequest {
+    count: Option<u8>,
+    period: Option<LeaderboardPeriod>,
+    started_at: Option<Timestamp>,
+    user_id: Option<UserId>
+}
+
+impl GetBitsLeaderboardRequest {
+    pub fn with_count(mut self, count: Option<u8>) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn with_period(mut self, period: Option<LeaderboardPeriod>) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// RFC3339 timestamp, ignored when the period is `All`.
+    pub fn with_started_at<T: Into<Timestamp>>(mut self, started_at: Option<T>) -> Self {
+        self.started_at = started_at.map(|x| x.into());
+        self
+    }
+
+    pub fn with_user_id<I: Into<UserId>>(mut self, user_id: Option<I>) -> Self {
+        self.user_id = user_id.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BitsLeaderboardResponse {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    pub rank: u32,
+    pub score: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+/// Both timestamps are `None` when the period is `All`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DateRange {
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub started_at: Option<Timestamp>,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub ended_at: Option<Timestamp>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BitsLeaderboardExtra {
+    pub date_range: DateRange,
+    pub total: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetBitsLeaderboard {
+    async fn get_bits_leaderboard(&self, request: GetBitsLeaderboardRequest) -> Result<ApiResult<BitsLeaderboardResponse, BitsLeaderboardExtra>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetBitsLeaderboard for BitsGroup {
+    async fn get_bits_leaderboard(&self, request: GetBitsLeaderboardRequest) -> Result<ApiResult<BitsLeaderboardResponse, BitsLeaderboardExtra>, Box<dyn Error>> {
+        if request.count.is_some_and(|x| !(1..=100).contains(&x)) {
+            return Err(String::from("Leaderboard count must be between 1 and 100!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("bits:read")]), "bits/leaderboard", &request).await?)
+    }
+}
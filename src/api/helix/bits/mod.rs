@@ -0,0 +1,24 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod leaderboard;
+pub use leaderboard::*;
+
+mod cheermotes;
+pub use cheermotes::*;
+
+mod transactions;
+pub use transactions::*;
+
+pub struct BitsGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl BitsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
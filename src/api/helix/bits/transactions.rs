@@ -0,0 +1,105 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}}, util::extend_url};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::BitsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetExtensionTransactionsRequest {
+    extension_id: String,
+
+    #[serde(skip_serializing)]
+    ids: Option<Vec<String>>,
+
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetExtensionTransactionsRequest {
+    pub fn with_extension_id<S: Into<String>>(mut self, extension_id: S) -> Self {
+        self.extension_id = extension_id.into();
+        self
+    }
+
+    pub fn with_ids<S: Into<String>>(mut self, ids: Vec<S>) -> Self {
+        self.ids = Some(ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProductCost {
+    pub amount: u64,
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductData {
+    pub domain: String,
+    pub sku: String,
+    pub cost: ProductCost,
+    pub in_development: bool,
+    pub display_name: String,
+    pub expiration: String,
+    pub broadcast: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExtensionTransactionResponse {
+    pub id: String,
+    pub timestamp: Timestamp,
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_login: String,
+    pub broadcaster_name: String,
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    pub product_type: String,
+    pub product_data: ProductData,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetExtensionTransactions {
+    /// Requires an app access token.
+    async fn get_extension_transactions(&self, request: GetExtensionTransactionsRequest) -> Result<ApiResult<ExtensionTransactionResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetExtensionTransactions for BitsGroup {
+    async fn get_extension_transactions(&self, request: GetExtensionTransactionsRequest) -> Result<ApiResult<ExtensionTransactionResponse>, Box<dyn Error>> {
+        if request.ids.as_ref().map_or(0, |x| x.len()) > 100 {
+            return Err(String::from("Cannot request more than 100 transactions at once!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, extend_url("extensions/transactions", "id", &request.ids)?, &request).await?)
+    }
+}
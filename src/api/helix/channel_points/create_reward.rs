@@ -0,0 +1,44 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use super::{ChannelPointsGroup, CustomRewardBody, CustomRewardResponse, custom_reward_setters};
+
+#[derive(Debug, Default, Serialize)]
+pub struct CreateCustomRewardRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    body: CustomRewardBody
+}
+
+impl CreateCustomRewardRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    custom_reward_setters!();
+}
+
+#[async_trait(?Send)]
+pub trait CreateCustomReward {
+    async fn create_custom_reward(&self, request: CreateCustomRewardRequest) -> Result<ApiResult<CustomRewardResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl CreateCustomReward for ChannelPointsGroup {
+    async fn create_custom_reward(&self, request: CreateCustomRewardRequest) -> Result<ApiResult<CustomRewardResponse>, Box<dyn Error>> {
+        if request.body.title.is_none() || request.body.cost.is_none() {
+            return Err(String::from("Reward title and cost are required!").into());
+        }
+
+        request.body.validate()?;
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("channel:manage:redemptions")]), "channel_points/custom_rewards", &request, &request.body).await?)
+    }
+}
@@ -0,0 +1,39 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::BroadcasterId;
+use super::ChannelPointsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct DeleteCustomRewardRequest {
+    broadcaster_id: BroadcasterId,
+    id: String
+}
+
+impl DeleteCustomRewardRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait DeleteCustomReward {
+    async fn delete_custom_reward(&self, request: DeleteCustomRewardRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl DeleteCustomReward for ChannelPointsGroup {
+    async fn delete_custom_reward(&self, request: DeleteCustomRewardRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::DELETE, Some(vec![String::from("channel:manage:redemptions")]), "channel_points/custom_rewards", &request).await?)
+    }
+}
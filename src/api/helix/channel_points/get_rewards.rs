@@ -0,0 +1,53 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::result::ApiResult}, util::extend_url};
+use crate::api::helix::id::BroadcasterId;
+use super::{ChannelPointsGroup, CustomRewardResponse};
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetCustomRewardsRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    ids: Option<Vec<String>>,
+
+    only_manageable_rewards: Option<bool>
+}
+
+impl GetCustomRewardsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_ids<S: Into<String>>(mut self, ids: Vec<S>) -> Self {
+        self.ids = Some(ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// Only return rewards created by this client ID, which are the only ones it can update.
+    pub fn with_only_manageable_rewards(mut self, only_manageable_rewards: Option<bool>) -> Self {
+        self.only_manageable_rewards = only_manageable_rewards;
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetCustomRewards {
+    async fn get_custom_rewards(&self, request: GetCustomRewardsRequest) -> Result<ApiResult<CustomRewardResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetCustomRewards for ChannelPointsGroup {
+    async fn get_custom_rewards(&self, request: GetCustomRewardsRequest) -> Result<ApiResult<CustomRewardResponse>, Box<dyn Error>> {
+        if request.ids.as_ref().map_or(0, |x| x.len()) > 50 {
+            return Err(String::from("Cannot request more than 50 rewards at once!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:redemptions")]), extend_url("channel_points/custom_rewards", "id", &request.ids)?, &request).await?)
+    }
+}
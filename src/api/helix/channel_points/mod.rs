@@ -0,0 +1,33 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod reward;
+pub use reward::*;
+
+mod get_rewards;
+pub use get_rewards::*;
+
+mod create_reward;
+pub use create_reward::*;
+
+mod update_reward;
+pub use update_reward::*;
+
+mod delete_reward;
+pub use delete_reward::*;
+
+mod redemptions;
+pub use redemptions::*;
+
+pub struct ChannelPointsGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl ChannelPointsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
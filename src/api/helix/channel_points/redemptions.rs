@@ -0,0 +1,207 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}}, util::extend_url};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::ChannelPointsGroup;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum RedemptionStatus {
+    Unfulfilled,
+    Fulfilled,
+    Canceled,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum RedemptionSort {
+    Oldest,
+    Newest
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetCustomRewardRedemptionsRequest {
+    broadcaster_id: BroadcasterId,
+    reward_id: String,
+    status: Option<RedemptionStatus>,
+
+    #[serde(skip_serializing)]
+    ids: Option<Vec<String>>,
+
+    sort: Option<RedemptionSort>,
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetCustomRewardRedemptionsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_reward_id<S: Into<String>>(mut self, reward_id: S) -> Self {
+        self.reward_id = reward_id.into();
+        self
+    }
+
+    /// Required unless `ids` is set.
+    pub fn with_status(mut self, status: Option<RedemptionStatus>) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn with_ids<S: Into<String>>(mut self, ids: Vec<S>) -> Self {
+        self.ids = Some(ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    pub fn with_sort(mut self, sort: Option<RedemptionSort>) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RedeemedReward {
+    pub id: String,
+    pub title: String,
+    pub prompt: String,
+    pub cost: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomRewardRedemptionResponse {
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_login: String,
+    pub broadcaster_name: String,
+    pub id: String,
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    pub user_input: String,
+    pub status: RedemptionStatus,
+    pub redeemed_at: Timestamp,
+    pub reward: RedeemedReward,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpdateRedemptionStatusBody {
+    status: RedemptionStatus
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpdateRedemptionStatusRequest {
+    #[serde(skip_serializing)]
+    ids: Option<Vec<String>>,
+
+    broadcaster_id: BroadcasterId,
+    reward_id: String,
+
+    #[serde(skip_serializing)]
+    body: UpdateRedemptionStatusBody
+}
+
+impl Default for UpdateRedemptionStatusRequest {
+    fn default() -> Self {
+        Self {
+            ids: None,
+            broadcaster_id: BroadcasterId::default(),
+            reward_id: String::new(),
+            body: UpdateRedemptionStatusBody {
+                status: RedemptionStatus::Fulfilled
+            }
+        }
+    }
+}
+
+impl UpdateRedemptionStatusRequest {
+    pub fn with_ids<S: Into<String>>(mut self, ids: Vec<S>) -> Self {
+        self.ids = Some(ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_reward_id<S: Into<String>>(mut self, reward_id: S) -> Self {
+        self.reward_id = reward_id.into();
+        self
+    }
+
+    /// Either `Fulfilled` or `Canceled`, canceling refunds the user's channel points.
+    pub fn with_status(mut self, status: RedemptionStatus) -> Self {
+        self.body.status = status;
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetCustomRewardRedemptions {
+    async fn get_custom_reward_redemptions(&self, request: GetCustomRewardRedemptionsRequest) -> Result<ApiResult<CustomRewardRedemptionResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetCustomRewardRedemptions for ChannelPointsGroup {
+    async fn get_custom_reward_redemptions(&self, request: GetCustomRewardRedemptionsRequest) -> Result<ApiResult<CustomRewardRedemptionResponse>, Box<dyn Error>> {
+        if request.status.is_none() && request.ids.is_none() {
+            return Err(String::from("Either a status or redemption IDs are required!").into());
+        }
+
+        if request.ids.as_ref().map_or(0, |x| x.len()) > 50 {
+            return Err(String::from("Cannot request more than 50 redemptions at once!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:redemptions")]), extend_url("channel_points/custom_rewards/redemptions", "id", &request.ids)?, &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UpdateRedemptionStatus {
+    async fn update_redemption_status(&self, request: UpdateRedemptionStatusRequest) -> Result<ApiResult<CustomRewardRedemptionResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UpdateRedemptionStatus for ChannelPointsGroup {
+    async fn update_redemption_status(&self, request: UpdateRedemptionStatusRequest) -> Result<ApiResult<CustomRewardRedemptionResponse>, Box<dyn Error>> {
+        if !matches!(request.body.status, RedemptionStatus::Fulfilled | RedemptionStatus::Canceled) {
+            return Err(String::from("Redemption status can only be updated to FULFILLED or CANCELED!").into());
+        }
+
+        if !request.ids.as_ref().is_some_and(|x| (1..=50).contains(&x.len())) {
+            return Err(String::from("Between 1 and 50 redemption IDs are required!").into());
+        }
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::PATCH, Some(vec![String::from("channel:manage:redemptions")]), extend_url("channel_points/custom_rewards/redemptions", "id", &request.ids)?, &request, &request.body).await?)
+    }
+}
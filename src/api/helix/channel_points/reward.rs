@@ -0,0 +1,194 @@
+
+use std::{collections::HashMap, error::Error};
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RewardImage {
+    pub url_1x: String,
+    pub url_2x: String,
+    pub url_4x: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MaxPerStreamSetting {
+    pub is_enabled: bool,
+    pub max_per_stream: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MaxPerUserPerStreamSetting {
+    pub is_enabled: bool,
+    pub max_per_user_per_stream: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GlobalCooldownSetting {
+    pub is_enabled: bool,
+    pub global_cooldown_seconds: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomRewardResponse {
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_login: String,
+    pub broadcaster_name: String,
+    pub id: String,
+    pub title: String,
+    pub prompt: String,
+    pub cost: u64,
+    pub image: Option<RewardImage>,
+    pub default_image: RewardImage,
+    pub background_color: String,
+    pub is_enabled: bool,
+    pub is_user_input_required: bool,
+    pub max_per_stream_setting: MaxPerStreamSetting,
+    pub max_per_user_per_stream_setting: MaxPerUserPerStreamSetting,
+    pub global_cooldown_setting: GlobalCooldownSetting,
+    pub is_paused: bool,
+    pub is_in_stock: bool,
+    pub should_redemptions_skip_request_queue: bool,
+    pub redemptions_redeemed_current_stream: Option<u64>,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub cooldown_expires_at: Option<Timestamp>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+/// Shared body of the create and update custom reward requests, unset fields are left unchanged.
+#[derive(Debug, Default, Serialize)]
+pub struct CustomRewardBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) prompt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cost: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) background_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) is_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) is_user_input_required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) is_max_per_stream_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) max_per_stream: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) is_max_per_user_per_stream_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) max_per_user_per_stream: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) is_global_cooldown_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) global_cooldown_seconds: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) is_paused: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) should_redemptions_skip_request_queue: Option<bool>
+}
+
+impl CustomRewardBody {
+    pub(crate) fn validate(&self) -> Result<(), Box<dyn Error>> {
+        if self.title.as_ref().is_some_and(|x| x.is_empty() || x.chars().count() > 45) {
+            return Err(String::from("Reward title must be between 1 and 45 characters!").into());
+        }
+
+        if self.prompt.as_ref().is_some_and(|x| x.chars().count() > 200) {
+            return Err(String::from("Reward prompt cannot be longer than 200 characters!").into());
+        }
+
+        if self.cost == Some(0) {
+            return Err(String::from("Reward cost must be at least 1!").into());
+        }
+
+        if self.max_per_stream == Some(0) || self.max_per_user_per_stream == Some(0) {
+            return Err(String::from("Reward redemption limits must be at least 1!").into());
+        }
+
+        if self.global_cooldown_seconds.is_some_and(|x| !(1..=604_800).contains(&x)) {
+            return Err(String::from("Reward global cooldown must be between 1 second and 7 days!").into());
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! custom_reward_setters {
+    () => {
+        pub fn with_title<S: Into<String>>(mut self, title: S) -> Self {
+            self.body.title = Some(title.into());
+            self
+        }
+
+        pub fn with_prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+            self.body.prompt = Some(prompt.into());
+            self
+        }
+
+        pub fn with_cost(mut self, cost: u64) -> Self {
+            self.body.cost = Some(cost);
+            self
+        }
+
+        /// Hex color, for example `#9147FF`.
+        pub fn with_background_color<S: Into<String>>(mut self, background_color: S) -> Self {
+            self.body.background_color = Some(background_color.into());
+            self
+        }
+
+        pub fn with_enabled(mut self, is_enabled: bool) -> Self {
+            self.body.is_enabled = Some(is_enabled);
+            self
+        }
+
+        pub fn with_user_input_required(mut self, is_user_input_required: bool) -> Self {
+            self.body.is_user_input_required = Some(is_user_input_required);
+            self
+        }
+
+        /// `None` removes the limit.
+        pub fn with_max_per_stream(mut self, max_per_stream: Option<u64>) -> Self {
+            self.body.is_max_per_stream_enabled = Some(max_per_stream.is_some());
+            self.body.max_per_stream = max_per_stream;
+            self
+        }
+
+        /// `None` removes the limit.
+        pub fn with_max_per_user_per_stream(mut self, max_per_user_per_stream: Option<u64>) -> Self {
+            self.body.is_max_per_user_per_stream_enabled = Some(max_per_user_per_stream.is_some());
+            self.body.max_per_user_per_stream = max_per_user_per_stream;
+            self
+        }
+
+        /// Cooldown in seconds, `None` removes the cooldown.
+        pub fn with_global_cooldown(mut self, global_cooldown_seconds: Option<u64>) -> Self {
+            self.body.is_global_cooldown_enabled = Some(global_cooldown_seconds.is_some());
+            self.body.global_cooldown_seconds = global_cooldown_seconds;
+            self
+        }
+
+        /// Redemptions are immediately marked as fulfilled instead of waiting in the queue.
+        pub fn with_skip_request_queue(mut self, should_redemptions_skip_request_queue: bool) -> Self {
+            self.body.should_redemptions_skip_request_queue = Some(should_redemptions_skip_request_queue);
+            self
+        }
+    };
+}
+
+pub(crate) use custom_reward_setters;
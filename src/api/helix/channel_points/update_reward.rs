@@ -0,0 +1,51 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use super::{ChannelPointsGroup, CustomRewardBody, CustomRewardResponse, custom_reward_setters};
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateCustomRewardRequest {
+    broadcaster_id: BroadcasterId,
+    id: String,
+
+    #[serde(skip_serializing)]
+    body: CustomRewardBody
+}
+
+impl UpdateCustomRewardRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    custom_reward_setters!();
+
+    pub fn with_paused(mut self, is_paused: bool) -> Self {
+        self.body.is_paused = Some(is_paused);
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UpdateCustomReward {
+    async fn update_custom_reward(&self, request: UpdateCustomRewardRequest) -> Result<ApiResult<CustomRewardResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UpdateCustomReward for ChannelPointsGroup {
+    async fn update_custom_reward(&self, request: UpdateCustomRewardRequest) -> Result<ApiResult<CustomRewardResponse>, Box<dyn Error>> {
+        request.body.validate()?;
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::PATCH, Some(vec![String::from("channel:manage:redemptions")]), "channel_points/custom_rewards", &request, &request.body).await?)
+    }
+}
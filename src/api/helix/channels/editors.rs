@@ -0,0 +1,45 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::ChannelsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetChannelEditorsRequest {
+    broadcaster_id: BroadcasterId
+}
+
+impl GetChannelEditorsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelEditorResponse {
+    pub user_id: UserId,
+    pub user_name: String,
+    pub created_at: Timestamp,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetChannelEditors {
+    async fn get_channel_editors(&self, request: GetChannelEditorsRequest) -> Result<ApiResult<ChannelEditorResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetChannelEditors for ChannelsGroup {
+    async fn get_channel_editors(&self, request: GetChannelEditorsRequest) -> Result<ApiResult<ChannelEditorResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:editors")]), "channels/editors", &request).await?)
+    }
+}
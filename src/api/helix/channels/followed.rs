@@ -0,0 +1,67 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::ChannelsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetFollowedChannelsRequest {
+    user_id: UserId,
+    broadcaster_id: Option<BroadcasterId>,
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetFollowedChannelsRequest {
+    pub fn with_user_id<I: Into<UserId>>(mut self, user_id: I) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    /// Only check whether the user follows this broadcaster.
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: Option<I>) -> Self {
+        self.broadcaster_id = broadcaster_id.map(|x| x.into());
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FollowedChannelResponse {
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_login: String,
+    pub broadcaster_name: String,
+    pub followed_at: Timestamp,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetFollowedChannels {
+    async fn get_followed_channels(&self, request: GetFollowedChannelsRequest) -> Result<ApiResult<FollowedChannelResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetFollowedChannels for ChannelsGroup {
+    async fn get_followed_channels(&self, request: GetFollowedChannelsRequest) -> Result<ApiResult<FollowedChannelResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("user:read:follows")]), "channels/followed", &request).await?)
+    }
+}
@@ -0,0 +1,67 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::ChannelsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetChannelFollowersRequest {
+    broadcaster_id: BroadcasterId,
+    user_id: Option<UserId>,
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetChannelFollowersRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// Only check whether this user follows the broadcaster.
+    pub fn with_user_id<I: Into<UserId>>(mut self, user_id: Option<I>) -> Self {
+        self.user_id = user_id.map(|x| x.into());
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelFollowerResponse {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    pub followed_at: Timestamp,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetChannelFollowers {
+    async fn get_channel_followers(&self, request: GetChannelFollowersRequest) -> Result<ApiResult<ChannelFollowerResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetChannelFollowers for ChannelsGroup {
+    async fn get_channel_followers(&self, request: GetChannelFollowersRequest) -> Result<ApiResult<ChannelFollowerResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("moderator:read:followers")]), "channels/followers", &request).await?)
+    }
+}
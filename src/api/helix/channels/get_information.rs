@@ -0,0 +1,68 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::result::ApiResult}, util::extend_url};
+use crate::api::helix::id::{BroadcasterId, GameId, deserialize_optional_id};
+use super::ChannelsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetChannelInformationRequest {
+    #[serde(skip_serializing)]
+    broadcaster_ids: Option<Vec<BroadcasterId>>
+}
+
+impl GetChannelInformationRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_ids.get_or_insert_with(Vec::new).push(broadcaster_id.into());
+        self
+    }
+
+    pub fn with_broadcaster_ids<I: Into<BroadcasterId>>(mut self, broadcaster_ids: Vec<I>) -> Self {
+        self.broadcaster_ids = Some(broadcaster_ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelInformationResponse {
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_login: String,
+    pub broadcaster_name: String,
+    pub broadcaster_language: String,
+    /// `None` when the broadcaster has not set a category.
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
+    pub game_id: Option<GameId>,
+    pub game_name: String,
+    pub title: String,
+    /// Always 0 unless the token belongs to the broadcaster.
+    pub delay: u32,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub content_classification_labels: Vec<String>,
+    #[serde(default)]
+    pub is_branded_content: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetChannelInformation {
+    async fn get_channel_information(&self, request: GetChannelInformationRequest) -> Result<ApiResult<ChannelInformationResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetChannelInformation for ChannelsGroup {
+    async fn get_channel_information(&self, request: GetChannelInformationRequest) -> Result<ApiResult<ChannelInformationResponse>, Box<dyn Error>> {
+        if request.broadcaster_ids.as_ref().map_or(0, |x| x.len()) > 100 {
+            return Err(String::from("Cannot request more than 100 broadcasters at once!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, extend_url("channels", "broadcaster_id", &request.broadcaster_ids)?, &request).await?)
+    }
+}
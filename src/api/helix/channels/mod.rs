@@ -0,0 +1,30 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod get_information;
+pub use get_information::*;
+
+mod modify_information;
+pub use modify_information::*;
+
+mod editors;
+pub use editors::*;
+
+mod followed;
+pub use followed::*;
+
+mod followers;
+pub use followers::*;
+
+pub struct ChannelsGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl ChannelsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
@@ -0,0 +1,109 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::{BroadcasterId, GameId};
+use super::ChannelsGroup;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContentClassificationLabel {
+    pub id: String,
+    pub is_enabled: bool
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyChannelInformationBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    game_id: Option<GameId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    broadcaster_language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delay: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_classification_labels: Option<Vec<ContentClassificationLabel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_branded_content: Option<bool>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyChannelInformationRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    body: ModifyChannelInformationBody
+}
+
+impl ModifyChannelInformationRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// Pass an empty string or `"0"` to unset the game.
+    pub fn with_game_id<I: Into<GameId>>(mut self, game_id: I) -> Self {
+        self.body.game_id = Some(game_id.into());
+        self
+    }
+
+    pub fn with_language<S: Into<String>>(mut self, language: S) -> Self {
+        self.body.broadcaster_language = Some(language.into());
+        self
+    }
+
+    pub fn with_title<S: Into<String>>(mut self, title: S) -> Self {
+        self.body.title = Some(title.into());
+        self
+    }
+
+    /// Stream delay in seconds, only available to partners.
+    pub fn with_delay(mut self, delay: u32) -> Self {
+        self.body.delay = Some(delay);
+        self
+    }
+
+    /// Pass an empty list to remove all tags.
+    pub fn with_tags<S: Into<String>>(mut self, tags: Vec<S>) -> Self {
+        self.body.tags = Some(tags.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    pub fn with_content_classification_label<S: Into<String>>(mut self, id: S, is_enabled: bool) -> Self {
+        self.body.content_classification_labels.get_or_insert_with(Vec::new).push(ContentClassificationLabel {
+            id: id.into(),
+            is_enabled
+        });
+        self
+    }
+
+    pub fn with_branded_content(mut self, is_branded_content: bool) -> Self {
+        self.body.is_branded_content = Some(is_branded_content);
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait ModifyChannelInformation {
+    async fn modify_channel_information(&self, request: ModifyChannelInformationRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl ModifyChannelInformation for ChannelsGroup {
+    async fn modify_channel_information(&self, request: ModifyChannelInformationRequest) -> Result<(), Box<dyn Error>> {
+        if request.body.title.as_ref().is_some_and(|x| x.is_empty()) {
+            return Err(String::from("Channel title cannot be empty!").into());
+        }
+
+        if request.body.tags.as_ref().is_some_and(|x| x.len() > 10) {
+            return Err(String::from("Cannot set more than 10 tags!").into());
+        }
+
+        Ok(self.client.call_empty_with_body(ApiEndpoint::Helix, Method::PATCH, Some(vec![String::from("channel:manage:broadcast")]), "channels", &request, &request.body).await?)
+    }
+}
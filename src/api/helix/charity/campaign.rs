@@ -0,0 +1,52 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::{result::ApiResult, amount::Amount}};
+use crate::api::helix::id::BroadcasterId;
+use super::CharityGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetCharityCampaignRequest {
+    broadcaster_id: BroadcasterId
+}
+
+impl GetCharityCampaignRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CharityCampaignResponse {
+    pub id: String,
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_login: String,
+    pub broadcaster_name: String,
+    pub charity_name: String,
+    pub charity_description: String,
+    pub charity_logo: String,
+    pub charity_website: String,
+    pub current_amount: Amount,
+    /// `None` when the broadcaster did not set a fundraising goal.
+    pub target_amount: Option<Amount>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetCharityCampaign {
+    async fn get_charity_campaign(&self, request: GetCharityCampaignRequest) -> Result<ApiResult<CharityCampaignResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetCharityCampaign for CharityGroup {
+    async fn get_charity_campaign(&self, request: GetCharityCampaignRequest) -> Result<ApiResult<CharityCampaignResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:charity")]), "charity/campaigns", &request).await?)
+    }
+}
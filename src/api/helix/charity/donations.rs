@@ -0,0 +1,61 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination, amount::Amount}};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::CharityGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetCharityCampaignDonationsRequest {
+    broadcaster_id: BroadcasterId,
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetCharityCampaignDonationsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CharityDonationResponse {
+    pub id: String,
+    pub campaign_id: String,
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    pub amount: Amount,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetCharityCampaignDonations {
+    async fn get_charity_campaign_donations(&self, request: GetCharityCampaignDonationsRequest) -> Result<ApiResult<CharityDonationResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetCharityCampaignDonations for CharityGroup {
+    async fn get_charity_campaign_donations(&self, request: GetCharityCampaignDonationsRequest) -> Result<ApiResult<CharityDonationResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:charity")]), "charity/donations", &request).await?)
+    }
+}
@@ -0,0 +1,21 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod campaign;
+pub use campaign::*;
+
+mod donations;
+pub use donations::*;
+
+pub struct CharityGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl CharityGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
@@ -0,0 +1,75 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::ChatGroup;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum AnnouncementColor {
+    Blue,
+    Green,
+    Orange,
+    Purple,
+    Primary
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct SendChatAnnouncementBody {
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<AnnouncementColor>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct SendChatAnnouncementRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+
+    #[serde(skip_serializing)]
+    body: SendChatAnnouncementBody
+}
+
+impl SendChatAnnouncementRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_message<S: Into<String>>(mut self, message: S) -> Self {
+        self.body.message = message.into();
+        self
+    }
+
+    /// `None` uses the channel's accent color.
+    pub fn with_color(mut self, color: Option<AnnouncementColor>) -> Self {
+        self.body.color = color;
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait SendChatAnnouncement {
+    async fn send_chat_announcement(&self, request: SendChatAnnouncementRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl SendChatAnnouncement for ChatGroup {
+    async fn send_chat_announcement(&self, request: SendChatAnnouncementRequest) -> Result<(), Box<dyn Error>> {
+        if request.body.message.is_empty() || request.body.message.chars().count() > 500 {
+            return Err(String::from("Announcement must be between 1 and 500 characters!").into());
+        }
+
+        Ok(self.client.call_empty_with_body(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("moderator:manage:announcements")]), "chat/announcements", &request, &request.body).await?)
+    }
+}
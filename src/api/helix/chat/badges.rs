@@ -0,0 +1,70 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use super::ChatGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetChannelChatBadgesRequest {
+    broadcaster_id: BroadcasterId
+}
+
+impl GetChannelChatBadgesRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatBadgeVersion {
+    pub id: String,
+    pub image_url_1x: String,
+    pub image_url_2x: String,
+    pub image_url_4x: String,
+    pub title: String,
+    pub description: String,
+    pub click_action: Option<String>,
+    pub click_url: Option<String>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatBadgeSetResponse {
+    pub set_id: String,
+    pub versions: Vec<ChatBadgeVersion>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetChannelChatBadges {
+    async fn get_channel_chat_badges(&self, request: GetChannelChatBadgesRequest) -> Result<ApiResult<ChatBadgeSetResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetChannelChatBadges for ChatGroup {
+    async fn get_channel_chat_badges(&self, request: GetChannelChatBadgesRequest) -> Result<ApiResult<ChatBadgeSetResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, "chat/badges", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetGlobalChatBadges {
+    async fn get_global_chat_badges(&self) -> Result<ApiResult<ChatBadgeSetResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetGlobalChatBadges for ChatGroup {
+    async fn get_global_chat_badges(&self) -> Result<ApiResult<ChatBadgeSetResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, "chat/badges/global", &()).await?)
+    }
+}
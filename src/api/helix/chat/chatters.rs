@@ -0,0 +1,77 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::ChatGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetChattersRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+    first: Option<u16>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetChattersRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    /// Up to 1000 chatters per page.
+    pub fn with_first(mut self, first: Option<u16>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatterResponse {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ChattersTotal {
+    pub total: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetChatters {
+    async fn get_chatters(&self, request: GetChattersRequest) -> Result<ApiResult<ChatterResponse, ChattersTotal>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetChatters for ChatGroup {
+    async fn get_chatters(&self, request: GetChattersRequest) -> Result<ApiResult<ChatterResponse, ChattersTotal>, Box<dyn Error>> {
+        if request.first.is_some_and(|x| !(1..=1000).contains(&x)) {
+            return Err(String::from("Chatters page size must be between 1 and 1000!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("moderator:read:chatters")]), "chat/chatters", &request).await?)
+    }
+}
@@ -0,0 +1,82 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::result::ApiResult}, util::extend_url};
+use crate::api::helix::id::UserId;
+use super::ChatGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetUserChatColorRequest {
+    #[serde(skip_serializing)]
+    user_ids: Option<Vec<UserId>>
+}
+
+impl GetUserChatColorRequest {
+    pub fn with_user_ids<I: Into<UserId>>(mut self, user_ids: Vec<I>) -> Self {
+        self.user_ids = Some(user_ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+}
+
+/// `color` is empty when the user never picked a color.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserChatColorResponse {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    pub color: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateUserChatColorRequest {
+    user_id: UserId,
+    color: String
+}
+
+impl UpdateUserChatColorRequest {
+    pub fn with_user_id<I: Into<UserId>>(mut self, user_id: I) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+
+    /// Either a named color such as `blue_violet`, or a hex color like `#9146FF` for Turbo and Prime users.
+    pub fn with_color<S: Into<String>>(mut self, color: S) -> Self {
+        self.color = color.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetUserChatColor {
+    async fn get_user_chat_color(&self, request: GetUserChatColorRequest) -> Result<ApiResult<UserChatColorResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetUserChatColor for ChatGroup {
+    async fn get_user_chat_color(&self, request: GetUserChatColorRequest) -> Result<ApiResult<UserChatColorResponse>, Box<dyn Error>> {
+        if !request.user_ids.as_ref().is_some_and(|x| (1..=100).contains(&x.len())) {
+            return Err(String::from("Between 1 and 100 user IDs are required!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, extend_url("chat/color", "user_id", &request.user_ids)?, &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UpdateUserChatColor {
+    async fn update_user_chat_color(&self, request: UpdateUserChatColorRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UpdateUserChatColor for ChatGroup {
+    async fn update_user_chat_color(&self, request: UpdateUserChatColorRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::PUT, Some(vec![String::from("user:manage:chat_color")]), "chat/color", &request).await?)
+    }
+}
@@ -0,0 +1,193 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize, Deserializer, de::DeserializeOwned};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::result::ApiResult}, util::extend_url};
+use crate::api::helix::id::{BroadcasterId, UserId, deserialize_optional_id};
+use super::ChatGroup;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum EmoteFormat {
+    Static,
+    Animated
+}
+
+impl EmoteFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmoteFormat::Static => "static",
+            EmoteFormat::Animated => "animated"
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum EmoteThemeMode {
+    Light,
+    Dark
+}
+
+impl EmoteThemeMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmoteThemeMode::Light => "light",
+            EmoteThemeMode::Dark => "dark"
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum EmoteScale {
+    #[serde(rename = "1.0")]
+    Small,
+    #[serde(rename = "2.0")]
+    Medium,
+    #[serde(rename = "3.0")]
+    Large
+}
+
+impl EmoteScale {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmoteScale::Small => "1.0",
+            EmoteScale::Medium => "2.0",
+            EmoteScale::Large => "3.0"
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmoteImages {
+    pub url_1x: String,
+    pub url_2x: String,
+    pub url_4x: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+/// Channel and emote set specific fields are `None` for global emotes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmoteResponse {
+    pub id: String,
+    pub name: String,
+    pub images: EmoteImages,
+    pub tier: Option<String>,
+    pub emote_type: Option<String>,
+    pub emote_set_id: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
+    pub owner_id: Option<UserId>,
+    /// Values this crate does not know yet are skipped.
+    #[serde(deserialize_with = "deserialize_known")]
+    pub format: Vec<EmoteFormat>,
+    #[serde(deserialize_with = "deserialize_known")]
+    pub scale: Vec<EmoteScale>,
+    #[serde(deserialize_with = "deserialize_known")]
+    pub theme_mode: Vec<EmoteThemeMode>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+fn deserialize_known<'de, D: Deserializer<'de>, T: DeserializeOwned>(deserializer: D) -> Result<Vec<T>, D::Error> {
+    Ok(Vec::<Value>::deserialize(deserializer)?.into_iter().filter_map(|x| serde_json::from_value(x).ok()).collect())
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EmoteTemplate {
+    pub template: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+impl EmoteTemplate {
+    /// Builds the image URL of an emote, only combinations listed by the emote are guaranteed to exist.
+    pub fn render(&self, id: &str, format: EmoteFormat, theme_mode: EmoteThemeMode, scale: EmoteScale) -> String {
+        self.template
+            .replace("{{id}}", id)
+            .replace("{{format}}", format.as_str())
+            .replace("{{theme_mode}}", theme_mode.as_str())
+            .replace("{{scale}}", scale.as_str())
+    }
+}
+
+impl ApiResult<EmoteResponse, EmoteTemplate> {
+    /// Renders the URL of an emote in this result.
+    pub fn render(&self, emote: &EmoteResponse, format: EmoteFormat, theme_mode: EmoteThemeMode, scale: EmoteScale) -> String {
+        self.extra.render(&emote.id, format, theme_mode, scale)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetChannelEmotesRequest {
+    broadcaster_id: BroadcasterId
+}
+
+impl GetChannelEmotesRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetEmoteSetsRequest {
+    #[serde(skip_serializing)]
+    emote_set_ids: Option<Vec<String>>
+}
+
+impl GetEmoteSetsRequest {
+    pub fn with_emote_set_ids<S: Into<String>>(mut self, emote_set_ids: Vec<S>) -> Self {
+        self.emote_set_ids = Some(emote_set_ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetChannelEmotes {
+    async fn get_channel_emotes(&self, request: GetChannelEmotesRequest) -> Result<ApiResult<EmoteResponse, EmoteTemplate>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetChannelEmotes for ChatGroup {
+    async fn get_channel_emotes(&self, request: GetChannelEmotesRequest) -> Result<ApiResult<EmoteResponse, EmoteTemplate>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, "chat/emotes", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetGlobalEmotes {
+    async fn get_global_emotes(&self) -> Result<ApiResult<EmoteResponse, EmoteTemplate>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetGlobalEmotes for ChatGroup {
+    async fn get_global_emotes(&self) -> Result<ApiResult<EmoteResponse, EmoteTemplate>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, "chat/emotes/global", &()).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetEmoteSets {
+    async fn get_emote_sets(&self, request: GetEmoteSetsRequest) -> Result<ApiResult<EmoteResponse, EmoteTemplate>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetEmoteSets for ChatGroup {
+    async fn get_emote_sets(&self, request: GetEmoteSetsRequest) -> Result<ApiResult<EmoteResponse, EmoteTemplate>, Box<dyn Error>> {
+        if !request.emote_set_ids.as_ref().is_some_and(|x| (1..=25).contains(&x.len())) {
+            return Err(String::from("Between 1 and 25 emote set IDs are required!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, extend_url("chat/emotes/set", "emote_set_id", &request.emote_set_ids)?, &request).await?)
+    }
+}
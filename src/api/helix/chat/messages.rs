@@ -0,0 +1,76 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::ChatGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct SendChatMessageRequest {
+    broadcaster_id: BroadcasterId,
+    sender_id: UserId,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_parent_message_id: Option<String>
+}
+
+impl SendChatMessageRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_sender_id<I: Into<UserId>>(mut self, sender_id: I) -> Self {
+        self.sender_id = sender_id.into();
+        self
+    }
+
+    pub fn with_message<S: Into<String>>(mut self, message: S) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    pub fn with_reply_parent_message_id<S: Into<String>>(mut self, reply_parent_message_id: Option<S>) -> Self {
+        self.reply_parent_message_id = reply_parent_message_id.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DropReason {
+    pub code: String,
+    pub message: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SendChatMessageResponse {
+    pub message_id: String,
+    pub is_sent: bool,
+    pub drop_reason: Option<DropReason>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait SendChatMessage {
+    async fn send_chat_message(&self, request: SendChatMessageRequest) -> Result<ApiResult<SendChatMessageResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl SendChatMessage for ChatGroup {
+    async fn send_chat_message(&self, request: SendChatMessageRequest) -> Result<ApiResult<SendChatMessageResponse>, Box<dyn Error>> {
+        if request.message.is_empty() || request.message.chars().count() > 500 {
+            return Err(String::from("Chat message must be between 1 and 500 characters!").into());
+        }
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("user:write:chat")]), "chat/messages", &(), &request).await?)
+    }
+}
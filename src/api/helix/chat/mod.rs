@@ -0,0 +1,39 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod chatters;
+pub use chatters::*;
+
+mod settings;
+pub use settings::*;
+
+mod emotes;
+pub use emotes::*;
+
+mod badges;
+pub use badges::*;
+
+mod announcements;
+pub use announcements::*;
+
+mod shoutouts;
+pub use shoutouts::*;
+
+mod color;
+pub use color::*;
+
+mod messages;
+pub use messages::*;
+
+pub struct ChatGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl ChatGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
@@ -0,0 +1,162 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::ChatGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetChatSettingsRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: Option<UserId>
+}
+
+impl GetChatSettingsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// Required to read the non-moderator chat delay settings.
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: Option<I>) -> Self {
+        self.moderator_id = moderator_id.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatSettingsResponse {
+    pub broadcaster_id: BroadcasterId,
+    pub emote_mode: bool,
+    pub follower_mode: bool,
+    pub follower_mode_duration: Option<u32>,
+    pub moderator_id: Option<UserId>,
+    pub non_moderator_chat_delay: Option<bool>,
+    pub non_moderator_chat_delay_duration: Option<u32>,
+    pub slow_mode: bool,
+    pub slow_mode_wait_time: Option<u32>,
+    pub subscriber_mode: bool,
+    pub unique_chat_mode: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateChatSettingsBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emote_mode: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    follower_mode: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    follower_mode_duration: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    non_moderator_chat_delay: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    non_moderator_chat_delay_duration: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slow_mode: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slow_mode_wait_time: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscriber_mode: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unique_chat_mode: Option<bool>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateChatSettingsRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+
+    #[serde(skip_serializing)]
+    body: UpdateChatSettingsBody
+}
+
+impl UpdateChatSettingsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_emote_mode(mut self, emote_mode: bool) -> Self {
+        self.body.emote_mode = Some(emote_mode);
+        self
+    }
+
+    /// Minutes a user must follow before chatting, `None` disables follower mode.
+    pub fn with_follower_mode(mut self, follower_mode_duration: Option<u32>) -> Self {
+        self.body.follower_mode = Some(follower_mode_duration.is_some());
+        self.body.follower_mode_duration = follower_mode_duration;
+        self
+    }
+
+    /// Seconds non-moderator messages are held back, `None` disables the delay.
+    pub fn with_non_moderator_chat_delay(mut self, non_moderator_chat_delay_duration: Option<u32>) -> Self {
+        self.body.non_moderator_chat_delay = Some(non_moderator_chat_delay_duration.is_some());
+        self.body.non_moderator_chat_delay_duration = non_moderator_chat_delay_duration;
+        self
+    }
+
+    /// Seconds users must wait between messages, `None` disables slow mode.
+    pub fn with_slow_mode(mut self, slow_mode_wait_time: Option<u32>) -> Self {
+        self.body.slow_mode = Some(slow_mode_wait_time.is_some());
+        self.body.slow_mode_wait_time = slow_mode_wait_time;
+        self
+    }
+
+    pub fn with_subscriber_mode(mut self, subscriber_mode: bool) -> Self {
+        self.body.subscriber_mode = Some(subscriber_mode);
+        self
+    }
+
+    pub fn with_unique_chat_mode(mut self, unique_chat_mode: bool) -> Self {
+        self.body.unique_chat_mode = Some(unique_chat_mode);
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetChatSettings {
+    async fn get_chat_settings(&self, request: GetChatSettingsRequest) -> Result<ApiResult<ChatSettingsResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetChatSettings for ChatGroup {
+    async fn get_chat_settings(&self, request: GetChatSettingsRequest) -> Result<ApiResult<ChatSettingsResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, "chat/settings", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UpdateChatSettings {
+    async fn update_chat_settings(&self, request: UpdateChatSettingsRequest) -> Result<ApiResult<ChatSettingsResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UpdateChatSettings for ChatGroup {
+    async fn update_chat_settings(&self, request: UpdateChatSettingsRequest) -> Result<ApiResult<ChatSettingsResponse>, Box<dyn Error>> {
+        if request.body.follower_mode_duration.is_some_and(|x| x > 129_600) {
+            return Err(String::from("Follower mode duration cannot be longer than 3 months!").into());
+        }
+
+        if request.body.non_moderator_chat_delay_duration.is_some_and(|x| ![2, 4, 6].contains(&x)) {
+            return Err(String::from("Non-moderator chat delay must be 2, 4 or 6 seconds!").into());
+        }
+
+        if request.body.slow_mode_wait_time.is_some_and(|x| !(3..=120).contains(&x)) {
+            return Err(String::from("Slow mode wait time must be between 3 and 120 seconds!").into());
+        }
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::PATCH, Some(vec![String::from("moderator:manage:chat_settings")]), "chat/settings", &request, &request.body).await?)
+    }
+}
@@ -0,0 +1,49 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::ChatGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct SendShoutoutRequest {
+    from_broadcaster_id: BroadcasterId,
+    to_broadcaster_id: BroadcasterId,
+    moderator_id: UserId
+}
+
+impl SendShoutoutRequest {
+    pub fn with_from_broadcaster_id<I: Into<BroadcasterId>>(mut self, from_broadcaster_id: I) -> Self {
+        self.from_broadcaster_id = from_broadcaster_id.into();
+        self
+    }
+
+    pub fn with_to_broadcaster_id<I: Into<BroadcasterId>>(mut self, to_broadcaster_id: I) -> Self {
+        self.to_broadcaster_id = to_broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait SendShoutout {
+    async fn send_shoutout(&self, request: SendShoutoutRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl SendShoutout for ChatGroup {
+    async fn send_shoutout(&self, request: SendShoutoutRequest) -> Result<(), Box<dyn Error>> {
+        if request.from_broadcaster_id == request.to_broadcaster_id {
+            return Err(String::from("Broadcasters cannot shout themselves out!").into());
+        }
+
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("moderator:manage:shoutouts")]), "chat/shoutouts", &request).await?)
+    }
+}
@@ -0,0 +1,349 @@
+
+use std::{error::Error, fmt, future::Future};
+use async_trait::async_trait;
+use futures::{stream, StreamExt};
+use reqwest::{Response, StatusCode};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::api::helix::id::ClipId;
+use super::{ClipsGroup, GetClipsResponse};
+
+/// Why the media URL of a clip cannot be derived, Helix itself never returns one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipMediaError {
+    NoThumbnail(ClipId),
+    /// Newer clips use thumbnails that do not share a path with their media, which is only
+    /// reachable through signed URLs. Pass a URL resolved some other way to `probe_media`.
+    UnsupportedThumbnail {
+        clip_id: ClipId,
+        thumbnail_url: String
+    }
+}
+
+impl fmt::Display for ClipMediaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClipMediaError::NoThumbnail(clip_id) => write!(f, "Clip {} has no thumbnail to derive its media URL from!", clip_id),
+            ClipMediaError::UnsupportedThumbnail { clip_id, thumbnail_url } => write!(f, "Clip {} has a thumbnail without a media URL: {}!", clip_id, thumbnail_url)
+        }
+    }
+}
+
+impl Error for ClipMediaError {}
+
+/// Derives the MP4 URL from a `...-preview-480x272.jpg` thumbnail.
+pub fn clip_media_url(clip: &GetClipsResponse) -> Result<String, ClipMediaError> {
+    if clip.thumbnail_url.is_empty() {
+        return Err(ClipMediaError::NoThumbnail(clip.id.clone()));
+    }
+
+    match clip.thumbnail_url.rfind("-preview-") {
+        Some(index) => Ok(format!("{}.mp4", &clip.thumbnail_url[..index])),
+        None => Err(ClipMediaError::UnsupportedThumbnail {
+            clip_id: clip.id.clone(),
+            thumbnail_url: clip.thumbnail_url.clone()
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClipMedia {
+    pub url: String,
+    /// `None` when the CDN does not report a length.
+    pub content_length: Option<u64>,
+    /// Without range support a resumed download skips the bytes that were already written.
+    pub accepts_ranges: bool
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DownloadProgress {
+    /// Includes the bytes already written before a resumed download.
+    pub downloaded: u64,
+    pub total: Option<u64>
+}
+
+fn header_content_length(response: &Response) -> Option<u64> {
+    response.headers()
+        .get("Content-Length")
+        .and_then(|x| x.to_str().ok())
+        .and_then(|x| x.parse().ok())
+}
+
+#[async_trait(?Send)]
+pub trait DownloadClip {
+    /// Resolves the media URL of a clip and reports its size without downloading it.
+    async fn resolve_clip_media(&self, clip: &GetClipsResponse) -> Result<ClipMedia, Box<dyn Error>>;
+
+    /// Like `resolve_clip_media` for a media URL resolved some other way.
+    async fn probe_media<S: Into<String>>(&self, url: S) -> Result<ClipMedia, Box<dyn Error>>;
+
+    /// Streams the media into `writer` starting at byte `offset`, which resumes a partial download
+    /// when `writer` already holds that many bytes. Returns the total size written, an offset past
+    /// the known length is an error.
+    async fn download_clip<W: AsyncWrite + Unpin, F: FnMut(DownloadProgress)>(&self, media: &ClipMedia, writer: &mut W, offset: u64, progress: F) -> Result<u64, Box<dyn Error>>;
+
+    /// Downloads up to `concurrency` clips at a time, results are in the same order as `clips`.
+    /// `open` returns the writer for a clip and how many bytes it already holds.
+    async fn download_clips<W, O, R, P>(&self, clips: &[GetClipsResponse], concurrency: usize, open: O, progress: P) -> Vec<Result<u64, Box<dyn Error>>>
+    where
+        W: AsyncWrite + Unpin,
+        O: FnMut(&GetClipsResponse) -> R,
+        R: Future<Output = Result<(W, u64), Box<dyn Error>>>,
+        P: Fn(&GetClipsResponse, DownloadProgress);
+}
+
+#[async_trait(?Send)]
+impl DownloadClip for ClipsGroup {
+    async fn resolve_clip_media(&self, clip: &GetClipsResponse) -> Result<ClipMedia, Box<dyn Error>> {
+        let url = clip_media_url(clip)?;
+        self.probe_media(url).await
+    }
+
+    async fn probe_media<S: Into<String>>(&self, url: S) -> Result<ClipMedia, Box<dyn Error>> {
+        let url = url.into();
+        let response = self.client.head(url.as_str()).await?;
+
+        Ok(ClipMedia {
+            content_length: header_content_length(&response),
+            accepts_ranges: response.headers().get("Accept-Ranges").is_some_and(|x| x.as_bytes() == b"bytes"),
+            url
+        })
+    }
+
+    async fn download_clip<W: AsyncWrite + Unpin, F: FnMut(DownloadProgress)>(&self, media: &ClipMedia, writer: &mut W, offset: u64, mut progress: F) -> Result<u64, Box<dyn Error>> {
+        if let Some(length) = media.content_length.filter(|x| offset > *x) {
+            return Err(format!("Clip download offset {} is past its {} bytes!", offset, length).into());
+        }
+
+        if media.content_length == Some(offset) {
+            progress(DownloadProgress {
+                downloaded: offset,
+                total: media.content_length
+            });
+            return Ok(offset);
+        }
+
+        let mut response = self.client.download_range(media.url.as_str(), offset).await?;
+
+        // A server that ignores the range sends the whole file, so the part we have is skipped.
+        let partial = response.status() == StatusCode::PARTIAL_CONTENT;
+        let mut skip = if partial { 0 } else { offset };
+        let total = media.content_length.or_else(|| header_content_length(&response).map(|x| if partial { x + offset } else { x }));
+        let mut downloaded = offset;
+
+        progress(DownloadProgress {
+            downloaded,
+            total
+        });
+
+        while let Some(chunk) = response.chunk().await? {
+            let skipped = skip.min(chunk.len() as u64);
+            skip -= skipped;
+
+            let chunk = &chunk[skipped as usize..];
+            if chunk.is_empty() {
+                continue;
+            }
+
+            writer.write_all(chunk).await?;
+            downloaded += chunk.len() as u64;
+            progress(DownloadProgress {
+                downloaded,
+                total
+            });
+        }
+        writer.flush().await?;
+
+        match total {
+            Some(total) if downloaded != total => Err(format!("Clip download ended after {} of {} bytes!", downloaded, total).into()),
+            _ => Ok(downloaded)
+        }
+    }
+
+    async fn download_clips<W, O, R, P>(&self, clips: &[GetClipsResponse], concurrency: usize, mut open: O, progress: P) -> Vec<Result<u64, Box<dyn Error>>>
+    where
+        W: AsyncWrite + Unpin,
+        O: FnMut(&GetClipsResponse) -> R,
+        R: Future<Output = Result<(W, u64), Box<dyn Error>>>,
+        P: Fn(&GetClipsResponse, DownloadProgress)
+    {
+        let progress = &progress;
+
+        stream::iter(clips.iter().map(|clip| {
+            let opened = open(clip);
+            async move {
+                let media = self.resolve_clip_media(clip).await?;
+                let (mut writer, offset) = opened.await?;
+                self.download_clip(&media, &mut writer, offset, |x| progress(clip, x)).await
+            }
+        }))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Client;
+    use serde_json::json;
+    use tokio::{net::TcpListener, io::AsyncReadExt, sync::oneshot};
+    use crate::TwitchClient;
+    use super::*;
+
+    const BODY: &[u8] = b"0123456789";
+
+    fn clip(thumbnail_url: &str) -> GetClipsResponse {
+        serde_json::from_value(json!({
+            "id": "AwkwardHelplessSalamanderSwiftRage",
+            "url": "https://clips.twitch.tv/AwkwardHelplessSalamanderSwiftRage",
+            "embed_url": "https://clips.twitch.tv/embed?clip=AwkwardHelplessSalamanderSwiftRage",
+            "broadcaster_id": "67955580",
+            "broadcaster_name": "ChewieMelodies",
+            "creator_id": "53834192",
+            "creator_name": "BlackNova03",
+            "video_id": "205586603",
+            "game_id": "488191",
+            "language": "en",
+            "title": "babymetal",
+            "view_count": 10,
+            "created_at": "2017-11-30T22:34:18Z",
+            "thumbnail_url": thumbnail_url,
+            "duration": 60,
+            "vod_offset": 480
+        })).unwrap()
+    }
+
+    async fn client() -> TwitchClient {
+        TwitchClient::builder()
+            .with_client(Client::builder().no_proxy().build().unwrap())
+            .with_client_id("client_id")
+            .with_access_token("access_token")
+            .build()
+            .await
+            .unwrap()
+    }
+
+    /// Answers a single request with `status` and `body`, sending back the request's `Range` header.
+    async fn serve(status: &'static str, body: &'static [u8]) -> (String, oneshot::Receiver<Option<String>>) {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/clip.mp4", listener.local_addr().unwrap());
+        let (sender, receiver) = oneshot::channel();
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..read]);
+            }
+
+            let request = String::from_utf8(request).unwrap();
+            let range = request.lines()
+                .find_map(|x| x.strip_prefix("range: ").or_else(|| x.strip_prefix("Range: ")))
+                .map(|x| x.to_owned());
+            let _ = sender.send(range);
+
+            let head = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", status, body.len());
+            socket.write_all(head.as_bytes()).await.unwrap();
+            socket.write_all(body).await.unwrap();
+        });
+
+        (url, receiver)
+    }
+
+    fn media(url: String, content_length: Option<u64>) -> ClipMedia {
+        ClipMedia {
+            url,
+            content_length,
+            accepts_ranges: true
+        }
+    }
+
+    #[test]
+    fn media_url() {
+        let legacy = clip("https://clips-media-assets2.twitch.tv/157589949-preview-480x272.jpg");
+        assert_eq!(clip_media_url(&legacy).unwrap(), "https://clips-media-assets2.twitch.tv/157589949.mp4");
+
+        let current = clip("https://static-cdn.jtvnw.net/twitch-clips-thumbnails-prod/AwkwardHelplessSalamanderSwiftRage/1/frame-0.jpg");
+        assert!(matches!(clip_media_url(&current), Err(ClipMediaError::UnsupportedThumbnail { .. })));
+
+        let missing = clip("");
+        assert_eq!(clip_media_url(&missing), Err(ClipMediaError::NoThumbnail(ClipId::new("AwkwardHelplessSalamanderSwiftRage"))));
+    }
+
+    #[tokio::test]
+    async fn resume_partial_content() {
+        let client = client().await;
+        let (url, range) = serve("206 Partial Content", &BODY[3..]).await;
+        let mut written = b"012".to_vec();
+        let mut updates = Vec::new();
+
+        let total = client.clips.download_clip(&media(url, None), &mut written, 3, |x| updates.push((x.downloaded, x.total))).await.unwrap();
+
+        assert_eq!(range.await.unwrap().as_deref(), Some("bytes=3-"));
+        assert_eq!(total, 10);
+        assert_eq!(written, BODY);
+        assert_eq!(updates.first(), Some(&(3, Some(10))));
+        assert_eq!(updates.last(), Some(&(10, Some(10))));
+    }
+
+    #[tokio::test]
+    async fn resume_full_content() {
+        let client = client().await;
+        let (url, range) = serve("200 OK", BODY).await;
+        let mut written = b"0123".to_vec();
+
+        let total = client.clips.download_clip(&media(url, Some(10)), &mut written, 4, |_| {}).await.unwrap();
+
+        assert_eq!(range.await.unwrap().as_deref(), Some("bytes=4-"));
+        assert_eq!(total, 10);
+        assert_eq!(written, BODY);
+    }
+
+    #[tokio::test]
+    async fn already_complete() {
+        let client = client().await;
+        let mut written = Vec::new();
+        let mut updates = Vec::new();
+
+        // Nothing listens here, a request would fail.
+        let media = media(String::from("http://127.0.0.1:9/clip.mp4"), Some(10));
+        let total = client.clips.download_clip(&media, &mut written, 10, |x| updates.push((x.downloaded, x.total))).await.unwrap();
+
+        assert_eq!(total, 10);
+        assert!(written.is_empty());
+        assert_eq!(updates, vec![(10, Some(10))]);
+    }
+
+    #[tokio::test]
+    async fn offset_past_end() {
+        let client = client().await;
+        let mut written = Vec::new();
+        let mut updates = Vec::new();
+
+        let media = media(String::from("http://127.0.0.1:9/clip.mp4"), Some(10));
+        let error = client.clips.download_clip(&media, &mut written, 12, |x| updates.push((x.downloaded, x.total))).await.unwrap_err();
+
+        assert_eq!(error.to_string(), "Clip download offset 12 is past its 10 bytes!");
+        assert!(written.is_empty());
+        assert!(updates.is_empty());
+    }
+
+    #[tokio::test]
+    async fn length_mismatch() {
+        let client = client().await;
+        let (url, range) = serve("200 OK", BODY).await;
+        let mut written = Vec::new();
+
+        let error = client.clips.download_clip(&media(url, Some(20)), &mut written, 0, |_| {}).await.unwrap_err();
+
+        assert_eq!(range.await.unwrap(), None);
+        assert_eq!(error.to_string(), "Clip download ended after 10 of 20 bytes!");
+        assert_eq!(written, BODY);
+    }
+}
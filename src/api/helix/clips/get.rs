@@ -1,46 +1,44 @@
 
-use std::error::Error;
+use std::{collections::HashMap, error::Error};
 use async_trait::async_trait;
 use serde::{Serialize, Deserialize};
+use serde_json::Value;
 use reqwest::Method;
 
 use crate::{api::{ApiEndpoint, helix::{result::ApiResult, pagination::*}}, util::extend_url};
+use crate::api::helix::id::{BroadcasterId, ClipId, GameId, UserId, VideoId, deserialize_optional_id};
+use crate::api::helix::time::Timestamp;
 use super::ClipsGroup;
 
 #[derive(Debug, Default, Serialize)]
 pub struct GetClipsRequest {
-    broadcaster_id: Option<String>,
-    game_id: Option<String>,
+    broadcaster_id: Option<BroadcasterId>,
+    game_id: Option<GameId>,
 
     #[serde(skip_serializing)]
-    ids: Option<Vec<String>>,
+    ids: Option<Vec<ClipId>>,
 
     first: Option<u8>,
-    started_at: Option<String>,
-    ended_at: Option<String>,
+    started_at: Option<Timestamp>,
+    ended_at: Option<Timestamp>,
 
     #[serde(flatten)]
     pagination: Pagination
 }
 
 impl GetClipsRequest {
-    pub fn with_broadcaster_id<S: Into<String>>(mut self, broadcaster_id: S) -> Self {
+    /// Exactly one of `with_broadcaster_id`, `with_game_id` or `with_ids` must be used.
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
         self.broadcaster_id = Some(broadcaster_id.into());
-        self.game_id = None;
-        self.ids = None;
         self
     }
 
-    pub fn with_game_id<S: Into<String>>(mut self, game_id: S) -> Self {
-        self.broadcaster_id = None;
+    pub fn with_game_id<I: Into<GameId>>(mut self, game_id: I) -> Self {
         self.game_id = Some(game_id.into());
-        self.ids = None;
         self
     }
 
-    pub fn with_ids<S: Into<String>>(mut self, id: Vec<S>) -> Self {
-        self.broadcaster_id = None;
-        self.game_id = None;
+    pub fn with_ids<I: Into<ClipId>>(mut self, id: Vec<I>) -> Self {
         self.ids = Some(id.into_iter().map(|x| x.into()).collect());
         self
     }
@@ -50,12 +48,12 @@ impl GetClipsRequest {
         self
     }
 
-    pub fn with_started_at<S: Into<String>>(mut self, started_at: Option<S>) -> Self {
+    pub fn with_started_at<T: Into<Timestamp>>(mut self, started_at: Option<T>) -> Self {
         self.started_at = started_at.map(|x| x.into());
         self
     }
 
-    pub fn with_ended_at<S: Into<String>>(mut self, ended_at: Option<S>) -> Self {
+    pub fn with_ended_at<T: Into<Timestamp>>(mut self, ended_at: Option<T>) -> Self {
         self.ended_at = ended_at.map(|x| x.into());
         self
     }
@@ -79,20 +77,33 @@ impl GetClipsRequest {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetClipsResponse {
-    pub broadcaster_id: String,
+    pub broadcaster_id: BroadcasterId,
     pub broadcaster_name: String,
-    pub created_at: String,
-    pub creator_id: String,
+    pub created_at: Timestamp,
+    pub creator_id: UserId,
     pub creator_name: String,
     pub embed_url: String,
-    pub game_id: String,
-    pub id: String,
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
+    pub game_id: Option<GameId>,
+    pub id: ClipId,
     pub language: String,
     pub thumbnail_url: String,
     pub title: String,
     pub url: String,
-    pub video_id: String,
-    pub view_count: u64
+    /// `None` when the video was deleted or the clip was not made from a VOD.
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
+    pub video_id: Option<VideoId>,
+    pub view_count: u64,
+    /// Length in seconds, with a precision of 0.1.
+    pub duration: f64,
+    /// Seconds into the VOD where the clip starts, `None` when there is no VOD.
+    #[serde(default)]
+    pub vod_offset: Option<u64>,
+    #[serde(default)]
+    pub is_featured: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
 }
 
 #[async_trait(?Send)]
@@ -103,6 +114,11 @@ pub trait GetClips {
 #[async_trait(?Send)]
 impl GetClips for ClipsGroup {
     async fn get_clips(&self, request: GetClipsRequest) -> Result<ApiResult<GetClipsResponse>, Box<dyn Error>> {
+        let selectors = [request.broadcaster_id.is_some(), request.game_id.is_some(), request.ids.is_some()];
+        if selectors.iter().filter(|x| **x).count() != 1 {
+            return Err(String::from("Exactly one of broadcaster_id, game_id or ids is required!").into());
+        }
+
         Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, extend_url("clips", "id", &request.ids)?, &request).await?)
     }
 }
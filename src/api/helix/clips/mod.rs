@@ -5,6 +5,11 @@ use crate::TwitchClientInner;
 mod get;
 pub use get::*;
 
+#[cfg(feature = "clips-download")]
+mod download;
+#[cfg(feature = "clips-download")]
+pub use download::*;
+
 pub struct ClipsGroup {
     client: Rc<TwitchClientInner>
 }
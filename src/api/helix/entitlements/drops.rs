@@ -0,0 +1,162 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}}, util::extend_url};
+use crate::api::helix::id::{GameId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::EntitlementsGroup;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum FulfillmentStatus {
+    Claimed,
+    Fulfilled,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetDropsEntitlementsRequest {
+    #[serde(skip_serializing)]
+    ids: Option<Vec<String>>,
+
+    user_id: Option<UserId>,
+    game_id: Option<GameId>,
+    fulfillment_status: Option<FulfillmentStatus>,
+    first: Option<u16>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetDropsEntitlementsRequest {
+    pub fn with_ids<S: Into<String>>(mut self, ids: Vec<S>) -> Self {
+        self.ids = Some(ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    pub fn with_user_id<I: Into<UserId>>(mut self, user_id: Option<I>) -> Self {
+        self.user_id = user_id.map(|x| x.into());
+        self
+    }
+
+    pub fn with_game_id<I: Into<GameId>>(mut self, game_id: Option<I>) -> Self {
+        self.game_id = game_id.map(|x| x.into());
+        self
+    }
+
+    pub fn with_fulfillment_status(mut self, fulfillment_status: Option<FulfillmentStatus>) -> Self {
+        self.fulfillment_status = fulfillment_status;
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u16>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DropsEntitlementResponse {
+    pub id: String,
+    pub benefit_id: String,
+    pub timestamp: Timestamp,
+    pub user_id: UserId,
+    pub game_id: GameId,
+    pub fulfillment_status: FulfillmentStatus,
+    pub last_updated: Timestamp,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateDropsEntitlementsBody {
+    entitlement_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fulfillment_status: Option<FulfillmentStatus>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateDropsEntitlementsRequest {
+    #[serde(skip_serializing)]
+    body: UpdateDropsEntitlementsBody
+}
+
+impl UpdateDropsEntitlementsRequest {
+    pub fn with_entitlement_ids<S: Into<String>>(mut self, entitlement_ids: Vec<S>) -> Self {
+        self.body.entitlement_ids = entitlement_ids.into_iter().map(|x| x.into()).collect();
+        self
+    }
+
+    pub fn with_fulfillment_status(mut self, fulfillment_status: FulfillmentStatus) -> Self {
+        self.body.fulfillment_status = Some(fulfillment_status);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum DropsEntitlementUpdateStatus {
+    Success,
+    InvalidId,
+    NotFound,
+    Unauthorized,
+    UpdateFailed,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DropsEntitlementUpdateResponse {
+    pub status: DropsEntitlementUpdateStatus,
+    pub ids: Vec<String>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetDropsEntitlements {
+    async fn get_drops_entitlements(&self, request: GetDropsEntitlementsRequest) -> Result<ApiResult<DropsEntitlementResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetDropsEntitlements for EntitlementsGroup {
+    async fn get_drops_entitlements(&self, request: GetDropsEntitlementsRequest) -> Result<ApiResult<DropsEntitlementResponse>, Box<dyn Error>> {
+        if request.first.is_some_and(|x| x > 1000) {
+            return Err(String::from("Cannot request more than 1000 entitlements per page!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, extend_url("entitlements/drops", "id", &request.ids)?, &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UpdateDropsEntitlements {
+    async fn update_drops_entitlements(&self, request: UpdateDropsEntitlementsRequest) -> Result<ApiResult<DropsEntitlementUpdateResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UpdateDropsEntitlements for EntitlementsGroup {
+    async fn update_drops_entitlements(&self, request: UpdateDropsEntitlementsRequest) -> Result<ApiResult<DropsEntitlementUpdateResponse>, Box<dyn Error>> {
+        if request.body.entitlement_ids.is_empty() || request.body.entitlement_ids.len() > 100 {
+            return Err(String::from("Must update between 1 and 100 entitlements!").into());
+        }
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::PATCH, None, "entitlements/drops", &request, &request.body).await?)
+    }
+}
@@ -0,0 +1,18 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod drops;
+pub use drops::*;
+
+pub struct EntitlementsGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl EntitlementsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
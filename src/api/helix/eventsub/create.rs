@@ -0,0 +1,107 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::result::ApiResult}, eventsub::EventSubscription};
+use super::{EventSubGroup, EventSubSubscription, EventSubTotals, EventSubTransport};
+
+#[derive(Debug, Serialize)]
+pub struct CreateEventSubSubscriptionBody {
+    #[serde(rename = "type")]
+    kind: String,
+    version: String,
+    condition: HashMap<String, String>,
+    transport: EventSubTransport
+}
+
+impl Default for CreateEventSubSubscriptionBody {
+    fn default() -> Self {
+        Self {
+            kind: String::new(),
+            version: String::from("1"),
+            condition: HashMap::new(),
+            transport: EventSubTransport::Websocket {
+                session_id: String::new()
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CreateEventSubSubscriptionRequest {
+    #[serde(skip_serializing)]
+    body: CreateEventSubSubscriptionBody
+}
+
+impl CreateEventSubSubscriptionRequest {
+    pub fn with_type<S: Into<String>>(mut self, kind: S) -> Self {
+        self.body.kind = kind.into();
+        self
+    }
+
+    pub fn with_version<S: Into<String>>(mut self, version: S) -> Self {
+        self.body.version = version.into();
+        self
+    }
+
+    pub fn with_condition<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.body.condition.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the type, version and condition from a typed subscription, e.g. `with_subscription::<StreamOnlineV1>(..)`.
+    pub fn with_subscription<T: EventSubscription>(mut self, condition: T::Condition) -> Self {
+        self.body.kind = String::from(T::TYPE);
+        self.body.version = String::from(T::VERSION);
+        self.body.condition.clear();
+
+        if let Ok(serde_json::Value::Object(condition)) = serde_json::to_value(condition) {
+            for (key, value) in condition {
+                if let serde_json::Value::String(value) = value {
+                    self.body.condition.insert(key, value);
+                }
+            }
+        }
+
+        self
+    }
+
+    pub fn with_transport(mut self, transport: EventSubTransport) -> Self {
+        self.body.transport = transport;
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait CreateEventSubSubscription {
+    async fn create_eventsub_subscription(&self, request: CreateEventSubSubscriptionRequest) -> Result<ApiResult<EventSubSubscription, EventSubTotals>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl CreateEventSubSubscription for EventSubGroup {
+    async fn create_eventsub_subscription(&self, request: CreateEventSubSubscriptionRequest) -> Result<ApiResult<EventSubSubscription, EventSubTotals>, Box<dyn Error>> {
+        if request.body.kind.is_empty() {
+            return Err(String::from("Subscription type is required!").into());
+        }
+
+        match &request.body.transport {
+            EventSubTransport::Webhook { callback, secret } => {
+                if !callback.starts_with("https://") {
+                    return Err(String::from("Webhook callback must use HTTPS!").into());
+                }
+
+                if secret.len() < 10 || secret.len() > 100 {
+                    return Err(String::from("Webhook secret must be between 10 and 100 characters!").into());
+                }
+            },
+            EventSubTransport::Websocket { session_id } if session_id.is_empty() => {
+                return Err(String::from("WebSocket session ID is required!").into());
+            },
+            _ => {}
+        }
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::POST, None, "eventsub/subscriptions", &request, &request.body).await?)
+    }
+}
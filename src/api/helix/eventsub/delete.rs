@@ -0,0 +1,32 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use super::EventSubGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct DeleteEventSubSubscriptionRequest {
+    id: String
+}
+
+impl DeleteEventSubSubscriptionRequest {
+    pub fn with_id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait DeleteEventSubSubscription {
+    async fn delete_eventsub_subscription(&self, request: DeleteEventSubSubscriptionRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl DeleteEventSubSubscription for EventSubGroup {
+    async fn delete_eventsub_subscription(&self, request: DeleteEventSubSubscriptionRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::DELETE, None, "eventsub/subscriptions", &request).await?)
+    }
+}
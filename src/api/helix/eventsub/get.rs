@@ -0,0 +1,64 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}}, eventsub::EventSubscription};
+use crate::api::helix::id::UserId;
+use super::{EventSubGroup, EventSubStatus, EventSubSubscription, EventSubTotals};
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetEventSubSubscriptionsRequest {
+    status: Option<EventSubStatus>,
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    user_id: Option<UserId>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetEventSubSubscriptionsRequest {
+    pub fn with_status(mut self, status: Option<EventSubStatus>) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn with_type<S: Into<String>>(mut self, kind: Option<S>) -> Self {
+        self.kind = kind.map(|x| x.into());
+        self
+    }
+
+    pub fn with_subscription_type<T: EventSubscription>(mut self) -> Self {
+        self.kind = Some(String::from(T::TYPE));
+        self
+    }
+
+    pub fn with_user_id<I: Into<UserId>>(mut self, user_id: Option<I>) -> Self {
+        self.user_id = user_id.map(|x| x.into());
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetEventSubSubscriptions {
+    async fn get_eventsub_subscriptions(&self, request: GetEventSubSubscriptionsRequest) -> Result<ApiResult<EventSubSubscription, EventSubTotals>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetEventSubSubscriptions for EventSubGroup {
+    async fn get_eventsub_subscriptions(&self, request: GetEventSubSubscriptionsRequest) -> Result<ApiResult<EventSubSubscription, EventSubTotals>, Box<dyn Error>> {
+        let filters = [request.status.is_some(), request.kind.is_some(), request.user_id.is_some()];
+        if filters.iter().filter(|x| **x).count() > 1 {
+            return Err(String::from("Only one of status, type or user ID can be used to filter subscriptions!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, "eventsub/subscriptions", &request).await?)
+    }
+}
@@ -0,0 +1,27 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod subscription;
+pub use subscription::*;
+
+mod create;
+pub use create::*;
+
+mod delete;
+pub use delete::*;
+
+mod get;
+pub use get::*;
+
+pub struct EventSubGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl EventSubGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
@@ -0,0 +1,104 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum EventSubStatus {
+    Enabled,
+    WebhookCallbackVerificationPending,
+    WebhookCallbackVerificationFailed,
+    NotificationFailuresExceeded,
+    AuthorizationRevoked,
+    ModeratorRemoved,
+    UserRemoved,
+    ChatUserBanned,
+    VersionRemoved,
+    BetaMaintenance,
+    WebsocketDisconnected,
+    WebsocketFailedPingPong,
+    WebsocketReceivedInboundTraffic,
+    WebsocketConnectionUnused,
+    WebsocketInternalError,
+    WebsocketNetworkTimeout,
+    WebsocketNetworkError,
+    WebsocketFailedToReconnect,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum EventSubTransportMethod {
+    Webhook,
+    Websocket,
+    Conduit,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+/// Where Twitch delivers notifications for a new subscription.
+#[derive(Debug, Serialize)]
+#[serde(tag = "method", rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum EventSubTransport {
+    /// Requires an app access token. The secret (10 to 100 characters) signs every notification.
+    Webhook {
+        callback: String,
+        secret: String
+    },
+    /// Requires a user access token, the session ID comes from the WebSocket welcome message.
+    Websocket {
+        session_id: String
+    },
+    Conduit {
+        conduit_id: String
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventSubTransportResponse {
+    pub method: EventSubTransportMethod,
+    pub callback: Option<String>,
+    pub session_id: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub connected_at: Option<Timestamp>,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub disconnected_at: Option<Timestamp>,
+    pub conduit_id: Option<String>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EventSubSubscription {
+    pub id: String,
+    pub status: EventSubStatus,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub version: String,
+    pub condition: HashMap<String, String>,
+    pub created_at: Timestamp,
+    pub transport: EventSubTransportResponse,
+    pub cost: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EventSubTotals {
+    pub total: u64,
+    pub total_cost: u64,
+    pub max_total_cost: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
@@ -0,0 +1,58 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use super::ExtensionsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetExtensionBitsProductsRequest {
+    should_include_all: Option<bool>
+}
+
+impl GetExtensionBitsProductsRequest {
+    /// Include disabled and expired products.
+    pub fn with_include_all(mut self, should_include_all: Option<bool>) -> Self {
+        self.should_include_all = should_include_all;
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BitsProductCost {
+    pub amount: u64,
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExtensionBitsProductResponse {
+    pub sku: String,
+    pub cost: BitsProductCost,
+    pub in_development: bool,
+    pub display_name: String,
+    pub expiration: String,
+    pub is_broadcast: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetExtensionBitsProducts {
+    /// Requires an app access token of the extension's client ID.
+    async fn get_extension_bits_products(&self, request: GetExtensionBitsProductsRequest) -> Result<ApiResult<ExtensionBitsProductResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetExtensionBitsProducts for ExtensionsGroup {
+    async fn get_extension_bits_products(&self, request: GetExtensionBitsProductsRequest) -> Result<ApiResult<ExtensionBitsProductResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, "bits/extensions", &request).await?)
+    }
+}
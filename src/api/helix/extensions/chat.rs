@@ -0,0 +1,65 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::BroadcasterId;
+use super::{ExtensionsGroup, REQUEST_TOKEN_TTL};
+
+#[derive(Debug, Default, Serialize)]
+pub struct SendExtensionChatMessageBody {
+    text: String,
+    extension_id: String,
+    extension_version: String
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct SendExtensionChatMessageRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    body: SendExtensionChatMessageBody
+}
+
+impl SendExtensionChatMessageRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_text<S: Into<String>>(mut self, text: S) -> Self {
+        self.body.text = text.into();
+        self
+    }
+
+    pub fn with_extension_id<S: Into<String>>(mut self, extension_id: S) -> Self {
+        self.body.extension_id = extension_id.into();
+        self
+    }
+
+    pub fn with_extension_version<S: Into<String>>(mut self, extension_version: S) -> Self {
+        self.body.extension_version = extension_version.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait SendExtensionChatMessage {
+    async fn send_extension_chat_message(&self, request: SendExtensionChatMessageRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl SendExtensionChatMessage for ExtensionsGroup {
+    async fn send_extension_chat_message(&self, request: SendExtensionChatMessageRequest) -> Result<(), Box<dyn Error>> {
+        if request.body.text.is_empty() || request.body.text.chars().count() > 280 {
+            return Err(String::from("Extension chat message must be between 1 and 280 characters!").into());
+        }
+
+        let signer = self.require_signer()?;
+        let jwt = signer.external_token(REQUEST_TOKEN_TTL)?;
+
+        Ok(self.client.call_empty_with_jwt(ApiEndpoint::Helix, Method::POST, signer.client_id(), &jwt, "extensions/chat", &request, Some(&request.body)).await?)
+    }
+}
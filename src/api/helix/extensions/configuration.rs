@@ -0,0 +1,151 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::result::ApiResult}, util::extend_url};
+use crate::api::helix::id::BroadcasterId;
+use super::{ExtensionsGroup, REQUEST_TOKEN_TTL};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ConfigurationSegment {
+    Broadcaster,
+    Developer,
+    Global,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetExtensionConfigurationSegmentRequest {
+    extension_id: String,
+
+    #[serde(skip_serializing)]
+    segments: Option<Vec<ConfigurationSegment>>,
+
+    broadcaster_id: Option<BroadcasterId>
+}
+
+impl GetExtensionConfigurationSegmentRequest {
+    pub fn with_extension_id<S: Into<String>>(mut self, extension_id: S) -> Self {
+        self.extension_id = extension_id.into();
+        self
+    }
+
+    pub fn with_segments(mut self, segments: Vec<ConfigurationSegment>) -> Self {
+        self.segments = Some(segments);
+        self
+    }
+
+    /// Required for the `Broadcaster` and `Developer` segments.
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: Option<I>) -> Self {
+        self.broadcaster_id = broadcaster_id.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExtensionConfigurationSegmentResponse {
+    pub segment: ConfigurationSegment,
+    pub broadcaster_id: Option<BroadcasterId>,
+    pub content: String,
+    pub version: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize)]
+pub struct SetExtensionConfigurationSegmentRequest {
+    extension_id: String,
+    segment: ConfigurationSegment,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    broadcaster_id: Option<BroadcasterId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>
+}
+
+impl Default for SetExtensionConfigurationSegmentRequest {
+    fn default() -> Self {
+        Self {
+            extension_id: String::new(),
+            segment: ConfigurationSegment::Global,
+            broadcaster_id: None,
+            content: None,
+            version: None
+        }
+    }
+}
+
+impl SetExtensionConfigurationSegmentRequest {
+    pub fn with_extension_id<S: Into<String>>(mut self, extension_id: S) -> Self {
+        self.extension_id = extension_id.into();
+        self
+    }
+
+    pub fn with_segment(mut self, segment: ConfigurationSegment) -> Self {
+        self.segment = segment;
+        self
+    }
+
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: Option<I>) -> Self {
+        self.broadcaster_id = broadcaster_id.map(|x| x.into());
+        self
+    }
+
+    /// Up to 5 KB of content, usually JSON.
+    pub fn with_content<S: Into<String>>(mut self, content: Option<S>) -> Self {
+        self.content = content.map(|x| x.into());
+        self
+    }
+
+    pub fn with_version<S: Into<String>>(mut self, version: Option<S>) -> Self {
+        self.version = version.map(|x| x.into());
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetExtensionConfigurationSegment {
+    async fn get_extension_configuration_segment(&self, request: GetExtensionConfigurationSegmentRequest) -> Result<ApiResult<ExtensionConfigurationSegmentResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetExtensionConfigurationSegment for ExtensionsGroup {
+    async fn get_extension_configuration_segment(&self, request: GetExtensionConfigurationSegmentRequest) -> Result<ApiResult<ExtensionConfigurationSegmentResponse>, Box<dyn Error>> {
+        let signer = self.require_signer()?;
+        let jwt = signer.external_token(REQUEST_TOKEN_TTL)?;
+
+        Ok(self.client.call_with_jwt(ApiEndpoint::Helix, Method::GET, signer.client_id(), &jwt, extend_url("extensions/configurations", "segment", &request.segments)?, &request, None::<&()>).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait SetExtensionConfigurationSegment {
+    async fn set_extension_configuration_segment(&self, request: SetExtensionConfigurationSegmentRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl SetExtensionConfigurationSegment for ExtensionsGroup {
+    async fn set_extension_configuration_segment(&self, request: SetExtensionConfigurationSegmentRequest) -> Result<(), Box<dyn Error>> {
+        if request.segment != ConfigurationSegment::Global && request.broadcaster_id.is_none() {
+            return Err(String::from("Broadcaster ID is required for the broadcaster and developer segments!").into());
+        }
+
+        if request.content.as_ref().is_some_and(|x| x.len() > 5 * 1024) {
+            return Err(String::from("Configuration segment cannot be larger than 5 KB!").into());
+        }
+
+        let signer = self.require_signer()?;
+        let jwt = signer.external_token(REQUEST_TOKEN_TTL)?;
+
+        Ok(self.client.call_empty_with_jwt(ApiEndpoint::Helix, Method::PUT, signer.client_id(), &jwt, "extensions/configurations", &(), Some(&request)).await?)
+    }
+}
@@ -0,0 +1,219 @@
+
+use std::{error::Error, time::{Duration, SystemTime, UNIX_EPOCH}};
+use base64::{Engine, engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD}};
+use hmac::{Hmac, Mac};
+use serde::{Serialize, Deserialize};
+use sha2::Sha256;
+
+/// Lifetime of the tokens signed for the extension endpoints.
+pub(crate) const REQUEST_TOKEN_TTL: Duration = Duration::from_secs(180);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExtensionRole {
+    External,
+    Broadcaster,
+    Moderator,
+    Viewer
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PubSubPerms {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub send: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub listen: Vec<String>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExtensionClaims {
+    /// Unix timestamp of when the token expires.
+    pub exp: u64,
+    pub role: ExtensionRole,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opaque_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pubsub_perms: Option<PubSubPerms>
+}
+
+/// Signs and verifies HS256 extension JWTs locally with the extension secret.
+pub struct ExtensionJwtSigner {
+    client_id: String,
+    secret: Vec<u8>,
+    owner_id: String
+}
+
+impl ExtensionJwtSigner {
+    /// `secret` is the base64 encoded secret from the extension's settings.
+    pub fn new(client_id: String, secret: &str, owner_id: String) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            client_id,
+            secret: STANDARD.decode(secret.trim())?,
+            owner_id
+        })
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn sign(&self, claims: &ExtensionClaims) -> Result<String, Box<dyn Error>> {
+        let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"HS256","typ":"JWT"}"#);
+        let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(claims)?);
+        let message = format!("{}.{}", header, payload);
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret)?;
+        mac.update(message.as_bytes());
+        let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+
+        Ok(format!("{}.{}", message, signature))
+    }
+
+    /// Checks the signature and expiry of a token, for example one sent by the extension frontend.
+    pub fn verify(&self, token: &str) -> Result<ExtensionClaims, Box<dyn Error>> {
+        let (message, signature) = token.rsplit_once('.')
+            .ok_or_else(|| String::from("Malformed extension JWT!"))?;
+        let (_, payload) = message.split_once('.')
+            .ok_or_else(|| String::from("Malformed extension JWT!"))?;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret)?;
+        mac.update(message.as_bytes());
+        mac.verify_slice(&URL_SAFE_NO_PAD.decode(signature)?)
+            .map_err(|_| String::from("Invalid extension JWT signature!"))?;
+
+        let claims: ExtensionClaims = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload)?)?;
+        if claims.exp <= unix_now() {
+            return Err(String::from("Extension JWT has expired!").into());
+        }
+
+        Ok(claims)
+    }
+
+    /// Token for calls made by the extension backend on its own behalf.
+    pub fn external_token(&self, ttl: Duration) -> Result<String, Box<dyn Error>> {
+        self.sign(&ExtensionClaims {
+            exp: unix_now() + ttl.as_secs(),
+            role: ExtensionRole::External,
+            user_id: Some(self.owner_id.clone()),
+            opaque_user_id: None,
+            channel_id: None,
+            pubsub_perms: None
+        })
+    }
+
+    /// Token acting as the broadcaster of `channel_id`, allowed to broadcast PubSub messages to that channel.
+    pub fn broadcaster_token(&self, channel_id: &str, ttl: Duration) -> Result<String, Box<dyn Error>> {
+        self.sign(&ExtensionClaims {
+            exp: unix_now() + ttl.as_secs(),
+            role: ExtensionRole::Broadcaster,
+            user_id: Some(channel_id.to_owned()),
+            opaque_user_id: None,
+            channel_id: Some(channel_id.to_owned()),
+            pubsub_perms: Some(PubSubPerms {
+                send: vec![String::from("broadcast")],
+                listen: vec![]
+            })
+        })
+    }
+
+    pub(crate) fn pubsub_token(&self, channel_id: &str, targets: Vec<String>, ttl: Duration) -> Result<String, Box<dyn Error>> {
+        self.sign(&ExtensionClaims {
+            exp: unix_now() + ttl.as_secs(),
+            role: ExtensionRole::External,
+            user_id: Some(self.owner_id.clone()),
+            opaque_user_id: None,
+            channel_id: Some(channel_id.to_owned()),
+            pubsub_perms: Some(PubSubPerms {
+                send: targets,
+                listen: vec![]
+            })
+        })
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |x| x.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use base64::{Engine, engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD}};
+    use super::*;
+
+    fn signer() -> ExtensionJwtSigner {
+        ExtensionJwtSigner::new(String::from("client"), &STANDARD.encode(b"extension secret"), String::from("1234")).unwrap()
+    }
+
+    fn claims(exp: u64) -> ExtensionClaims {
+        ExtensionClaims {
+            exp,
+            role: ExtensionRole::Broadcaster,
+            user_id: Some(String::from("5678")),
+            opaque_user_id: None,
+            channel_id: Some(String::from("5678")),
+            pubsub_perms: None
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let signer = signer();
+        let token = signer.external_token(Duration::from_secs(60)).unwrap();
+        let claims = signer.verify(&token).unwrap();
+
+        assert_eq!(claims.role, ExtensionRole::External);
+        assert_eq!(claims.user_id.as_deref(), Some("1234"));
+        assert!(claims.exp > unix_now());
+    }
+
+    #[test]
+    fn tampered_payload() {
+        let signer = signer();
+        let token = signer.sign(&claims(unix_now() + 60)).unwrap();
+        let parts: Vec<&str> = token.split('.').collect();
+
+        let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&ExtensionClaims {
+            role: ExtensionRole::External,
+            ..claims(unix_now() + 60)
+        }).unwrap());
+        let tampered = format!("{}.{}.{}", parts[0], payload, parts[2]);
+
+        assert_eq!(signer.verify(&tampered).unwrap_err().to_string(), "Invalid extension JWT signature!");
+    }
+
+    #[test]
+    fn other_secret() {
+        let token = signer().sign(&claims(unix_now() + 60)).unwrap();
+        let other = ExtensionJwtSigner::new(String::from("client"), &STANDARD.encode(b"other secret"), String::from("1234")).unwrap();
+
+        assert!(other.verify(&token).is_err());
+    }
+
+    #[test]
+    fn expired() {
+        let signer = signer();
+        let token = signer.sign(&claims(unix_now() - 1)).unwrap();
+
+        assert_eq!(signer.verify(&token).unwrap_err().to_string(), "Extension JWT has expired!");
+    }
+
+    #[test]
+    fn malformed() {
+        let signer = signer();
+
+        assert_eq!(signer.verify("nodots").unwrap_err().to_string(), "Malformed extension JWT!");
+        assert_eq!(signer.verify("one.dot").unwrap_err().to_string(), "Malformed extension JWT!");
+        assert!(signer.verify("a.b.!!!").is_err());
+        assert!(signer.verify("").is_err());
+    }
+
+    #[test]
+    fn invalid_secret() {
+        assert!(ExtensionJwtSigner::new(String::from("client"), "not base64!", String::from("1234")).is_err());
+    }
+}
@@ -0,0 +1,60 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}};
+use crate::api::helix::id::{BroadcasterId, GameId};
+use super::ExtensionsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetExtensionLiveChannelsRequest {
+    extension_id: String,
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetExtensionLiveChannelsRequest {
+    pub fn with_extension_id<S: Into<String>>(mut self, extension_id: S) -> Self {
+        self.extension_id = extension_id.into();
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExtensionLiveChannelResponse {
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_name: String,
+    pub game_name: String,
+    pub game_id: GameId,
+    pub title: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetExtensionLiveChannels {
+    async fn get_extension_live_channels(&self, request: GetExtensionLiveChannelsRequest) -> Result<ApiResult<ExtensionLiveChannelResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetExtensionLiveChannels for ExtensionsGroup {
+    async fn get_extension_live_channels(&self, request: GetExtensionLiveChannelsRequest) -> Result<ApiResult<ExtensionLiveChannelResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, "extensions/live", &request).await?)
+    }
+}
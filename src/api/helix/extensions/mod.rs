@@ -0,0 +1,51 @@
+
+use std::{error::Error, rc::Rc};
+use crate::TwitchClientInner;
+
+mod jwt;
+pub use jwt::*;
+
+mod configuration;
+pub use configuration::*;
+
+mod required_configuration;
+pub use required_configuration::*;
+
+mod pubsub;
+pub use pubsub::*;
+
+mod live;
+pub use live::*;
+
+mod secrets;
+pub use secrets::*;
+
+mod chat;
+pub use chat::*;
+
+mod bits_products;
+pub use bits_products::*;
+
+pub struct ExtensionsGroup {
+    client: Rc<TwitchClientInner>,
+    signer: Option<ExtensionJwtSigner>
+}
+
+impl ExtensionsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>, signer: Option<ExtensionJwtSigner>) -> Self {
+        Self {
+            client,
+            signer
+        }
+    }
+
+    /// The signer configured with `TwitchClientBuilder::with_extension_secret`.
+    pub fn jwt_signer(&self) -> Option<&ExtensionJwtSigner> {
+        self.signer.as_ref()
+    }
+
+    fn require_signer(&self) -> Result<&ExtensionJwtSigner, Box<dyn Error>> {
+        self.signer.as_ref()
+            .ok_or_else(|| String::from("Extension secret is not configured!").into())
+    }
+}
@@ -0,0 +1,91 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::BroadcasterId;
+use super::{ExtensionsGroup, REQUEST_TOKEN_TTL};
+
+#[derive(Debug, Default, Serialize)]
+pub struct SendExtensionPubSubMessageRequest {
+    target: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    broadcaster_id: Option<BroadcasterId>,
+    is_global_broadcast: bool,
+    message: String
+}
+
+impl SendExtensionPubSubMessageRequest {
+    /// Sends to every viewer of the extension on the broadcaster's channel.
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = Some(broadcaster_id.into());
+        self.is_global_broadcast = false;
+        self.target = vec![String::from("broadcast")];
+        self
+    }
+
+    /// Sends to every viewer of the extension on every channel.
+    pub fn with_global_broadcast(mut self) -> Self {
+        self.broadcaster_id = None;
+        self.is_global_broadcast = true;
+        self.target = vec![String::from("global")];
+        self
+    }
+
+    /// Sends to a single user on the broadcaster's channel, `user_id` may be opaque.
+    pub fn with_whisper<I: Into<BroadcasterId>, S: Into<String>>(mut self, broadcaster_id: I, user_id: S) -> Self {
+        self.broadcaster_id = Some(broadcaster_id.into());
+        self.is_global_broadcast = false;
+        self.target = vec![format!("whisper-{}", user_id.into())];
+        self
+    }
+
+    /// Up to 5 KB, usually JSON.
+    pub fn with_message<S: Into<String>>(mut self, message: S) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait SendExtensionPubSubMessage {
+    async fn send_extension_pubsub_message(&self, request: SendExtensionPubSubMessageRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl SendExtensionPubSubMessage for ExtensionsGroup {
+    async fn send_extension_pubsub_message(&self, request: SendExtensionPubSubMessageRequest) -> Result<(), Box<dyn Error>> {
+        if request.target.is_empty() {
+            return Err(String::from("A broadcaster, whisper or global target is required!").into());
+        }
+
+        if request.message.len() > 5 * 1024 {
+            return Err(String::from("PubSub message cannot be larger than 5 KB!").into());
+        }
+
+        let signer = self.require_signer()?;
+        let channel_id = request.broadcaster_id.as_deref().unwrap_or("all");
+        let jwt = signer.pubsub_token(channel_id, request.target.clone(), REQUEST_TOKEN_TTL)?;
+
+        Ok(self.client.call_empty_with_jwt(ApiEndpoint::Helix, Method::POST, signer.client_id(), &jwt, "extensions/pubsub", &(), Some(&request)).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SendExtensionPubSubMessageRequest;
+
+    #[test]
+    fn global_broadcast_omits_broadcaster() {
+        let request = SendExtensionPubSubMessageRequest::default()
+            .with_broadcaster_id("1234")
+            .with_global_broadcast()
+            .with_message("hello");
+
+        let body = serde_json::to_value(&request).unwrap();
+        assert!(body.get("broadcaster_id").is_none());
+        assert_eq!(body["target"], serde_json::json!(["global"]));
+    }
+}
@@ -0,0 +1,62 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::BroadcasterId;
+use super::{ExtensionsGroup, REQUEST_TOKEN_TTL};
+
+#[derive(Debug, Default, Serialize)]
+pub struct SetExtensionRequiredConfigurationBody {
+    extension_id: String,
+    extension_version: String,
+    required_configuration: String
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct SetExtensionRequiredConfigurationRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    body: SetExtensionRequiredConfigurationBody
+}
+
+impl SetExtensionRequiredConfigurationRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_extension_id<S: Into<String>>(mut self, extension_id: S) -> Self {
+        self.body.extension_id = extension_id.into();
+        self
+    }
+
+    pub fn with_extension_version<S: Into<String>>(mut self, extension_version: S) -> Self {
+        self.body.extension_version = extension_version.into();
+        self
+    }
+
+    /// Must match the required configuration string set in the extension's settings.
+    pub fn with_required_configuration<S: Into<String>>(mut self, required_configuration: S) -> Self {
+        self.body.required_configuration = required_configuration.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait SetExtensionRequiredConfiguration {
+    async fn set_extension_required_configuration(&self, request: SetExtensionRequiredConfigurationRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl SetExtensionRequiredConfiguration for ExtensionsGroup {
+    async fn set_extension_required_configuration(&self, request: SetExtensionRequiredConfigurationRequest) -> Result<(), Box<dyn Error>> {
+        let signer = self.require_signer()?;
+        let jwt = signer.external_token(REQUEST_TOKEN_TTL)?;
+
+        Ok(self.client.call_empty_with_jwt(ApiEndpoint::Helix, Method::PUT, signer.client_id(), &jwt, "extensions/required_configuration", &request, Some(&request.body)).await?)
+    }
+}
@@ -0,0 +1,95 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::time::Timestamp;
+use super::{ExtensionsGroup, REQUEST_TOKEN_TTL};
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetExtensionSecretsRequest {
+    extension_id: String
+}
+
+impl GetExtensionSecretsRequest {
+    pub fn with_extension_id<S: Into<String>>(mut self, extension_id: S) -> Self {
+        self.extension_id = extension_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CreateExtensionSecretRequest {
+    extension_id: String,
+    delay: Option<u32>
+}
+
+impl CreateExtensionSecretRequest {
+    pub fn with_extension_id<S: Into<String>>(mut self, extension_id: S) -> Self {
+        self.extension_id = extension_id.into();
+        self
+    }
+
+    /// Seconds before the new secret becomes active, at least 300.
+    pub fn with_delay(mut self, delay: Option<u32>) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExtensionSecret {
+    /// Base64 encoded secret, usable with `ExtensionJwtSigner::new`.
+    pub content: String,
+    pub active_at: Timestamp,
+    pub expires_at: Timestamp,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExtensionSecretsResponse {
+    pub format_version: u32,
+    pub secrets: Vec<ExtensionSecret>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetExtensionSecrets {
+    async fn get_extension_secrets(&self, request: GetExtensionSecretsRequest) -> Result<ApiResult<ExtensionSecretsResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetExtensionSecrets for ExtensionsGroup {
+    async fn get_extension_secrets(&self, request: GetExtensionSecretsRequest) -> Result<ApiResult<ExtensionSecretsResponse>, Box<dyn Error>> {
+        let signer = self.require_signer()?;
+        let jwt = signer.external_token(REQUEST_TOKEN_TTL)?;
+
+        Ok(self.client.call_with_jwt(ApiEndpoint::Helix, Method::GET, signer.client_id(), &jwt, "extensions/jwt/secrets", &request, None::<&()>).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait CreateExtensionSecret {
+    async fn create_extension_secret(&self, request: CreateExtensionSecretRequest) -> Result<ApiResult<ExtensionSecretsResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl CreateExtensionSecret for ExtensionsGroup {
+    async fn create_extension_secret(&self, request: CreateExtensionSecretRequest) -> Result<ApiResult<ExtensionSecretsResponse>, Box<dyn Error>> {
+        if request.delay.is_some_and(|x| x < 300) {
+            return Err(String::from("Secret activation delay must be at least 300 seconds!").into());
+        }
+
+        let signer = self.require_signer()?;
+        let jwt = signer.external_token(REQUEST_TOKEN_TTL)?;
+
+        Ok(self.client.call_with_jwt(ApiEndpoint::Helix, Method::POST, signer.client_id(), &jwt, "extensions/jwt/secrets", &request, None::<&()>).await?)
+    }
+}
@@ -0,0 +1,66 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::Timestamp;
+use super::GoalsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetCreatorGoalsRequest {
+    broadcaster_id: BroadcasterId
+}
+
+impl GetCreatorGoalsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum GoalType {
+    Follower,
+    Subscription,
+    SubscriptionCount,
+    NewSubscription,
+    NewSubscriptionCount,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatorGoalResponse {
+    pub id: String,
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_login: String,
+    pub broadcaster_name: String,
+    #[serde(rename = "type")]
+    pub kind: GoalType,
+    pub description: String,
+    pub current_amount: u64,
+    pub target_amount: u64,
+    pub created_at: Timestamp,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetCreatorGoals {
+    async fn get_creator_goals(&self, request: GetCreatorGoalsRequest) -> Result<ApiResult<CreatorGoalResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetCreatorGoals for GoalsGroup {
+    async fn get_creator_goals(&self, request: GetCreatorGoalsRequest) -> Result<ApiResult<CreatorGoalResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:goals")]), "goals", &request).await?)
+    }
+}
@@ -0,0 +1,18 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod get;
+pub use get::*;
+
+pub struct GoalsGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl GoalsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
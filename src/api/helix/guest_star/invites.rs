@@ -0,0 +1,143 @@
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::GuestStarGroup;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum GuestStarInviteStatus {
+    Invited,
+    Accepted,
+    /// The guest is in the backstage and can be assigned a slot.
+    Ready,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuestStarInviteResponse {
+    pub user_id: UserId,
+    pub invited_at: Timestamp,
+    pub status: GuestStarInviteStatus,
+    pub is_video_enabled: bool,
+    pub is_audio_enabled: bool,
+    pub is_video_available: bool,
+    pub is_audio_available: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetGuestStarInvitesRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+    session_id: String
+}
+
+impl GetGuestStarInvitesRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// The broadcaster or one of their moderators.
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_session_id<S: Into<String>>(mut self, session_id: S) -> Self {
+        self.session_id = session_id.into();
+        self
+    }
+}
+
+/// Used to both send and revoke an invite.
+#[derive(Debug, Default, Serialize)]
+pub struct GuestStarInviteRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+    session_id: String,
+    guest_id: UserId
+}
+
+impl GuestStarInviteRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// The broadcaster or one of their moderators.
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_session_id<S: Into<String>>(mut self, session_id: S) -> Self {
+        self.session_id = session_id.into();
+        self
+    }
+
+    pub fn with_guest_id<I: Into<UserId>>(mut self, guest_id: I) -> Self {
+        self.guest_id = guest_id.into();
+        self
+    }
+
+    fn validate(&self) -> Result<(), Box<dyn Error>> {
+        if self.session_id.is_empty() || self.guest_id.is_empty() {
+            return Err(String::from("Guest Star session and guest IDs are required!").into());
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetGuestStarInvites {
+    async fn get_guest_star_invites(&self, request: GetGuestStarInvitesRequest) -> Result<ApiResult<GuestStarInviteResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetGuestStarInvites for GuestStarGroup {
+    async fn get_guest_star_invites(&self, request: GetGuestStarInvitesRequest) -> Result<ApiResult<GuestStarInviteResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:guest_star")]), "guest_star/invites", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait SendGuestStarInvite {
+    async fn send_guest_star_invite(&self, request: GuestStarInviteRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl SendGuestStarInvite for GuestStarGroup {
+    async fn send_guest_star_invite(&self, request: GuestStarInviteRequest) -> Result<(), Box<dyn Error>> {
+        request.validate()?;
+
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("channel:manage:guest_star")]), "guest_star/invites", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait DeleteGuestStarInvite {
+    /// Also removes the guest from their slot if they were already assigned one.
+    async fn delete_guest_star_invite(&self, request: GuestStarInviteRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl DeleteGuestStarInvite for GuestStarGroup {
+    async fn delete_guest_star_invite(&self, request: GuestStarInviteRequest) -> Result<(), Box<dyn Error>> {
+        request.validate()?;
+
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::DELETE, Some(vec![String::from("channel:manage:guest_star")]), "guest_star/invites", &request).await?)
+    }
+}
@@ -0,0 +1,28 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod settings;
+pub use settings::*;
+
+mod session;
+pub use session::*;
+
+mod invites;
+pub use invites::*;
+
+mod slots;
+pub use slots::*;
+
+/// Guest Star is a beta API, Twitch may still change it.
+pub struct GuestStarGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl GuestStarGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
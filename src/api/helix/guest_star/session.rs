@@ -0,0 +1,178 @@
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::GuestStarGroup;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuestMediaSettings {
+    /// Whether the guest has the device at all.
+    pub is_available: bool,
+    pub is_host_enabled: bool,
+    pub is_guest_enabled: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuestStarGuest {
+    /// `"0"` is the host's slot.
+    pub slot_id: String,
+    pub is_live: bool,
+    pub user_id: UserId,
+    pub user_display_name: String,
+    pub user_login: String,
+    /// From 0 to 100.
+    pub volume: u8,
+    pub assigned_at: Timestamp,
+    pub audio_settings: GuestMediaSettings,
+    pub video_settings: GuestMediaSettings,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuestStarSessionResponse {
+    pub id: String,
+    pub guests: Vec<GuestStarGuest>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetGuestStarSessionRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId
+}
+
+impl GetGuestStarSessionRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// The broadcaster or one of their moderators.
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CreateGuestStarSessionRequest {
+    broadcaster_id: BroadcasterId
+}
+
+impl CreateGuestStarSessionRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct EndGuestStarSessionRequest {
+    broadcaster_id: BroadcasterId,
+    session_id: String
+}
+
+impl EndGuestStarSessionRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_session_id<S: Into<String>>(mut self, session_id: S) -> Self {
+        self.session_id = session_id.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetGuestStarSession {
+    async fn get_guest_star_session(&self, request: GetGuestStarSessionRequest) -> Result<ApiResult<GuestStarSessionResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetGuestStarSession for GuestStarGroup {
+    async fn get_guest_star_session(&self, request: GetGuestStarSessionRequest) -> Result<ApiResult<GuestStarSessionResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:guest_star")]), "guest_star/session", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait CreateGuestStarSession {
+    /// Only the broadcaster can start a session, and only one can be active at a time.
+    async fn create_guest_star_session(&self, request: CreateGuestStarSessionRequest) -> Result<ApiResult<GuestStarSessionResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl CreateGuestStarSession for GuestStarGroup {
+    async fn create_guest_star_session(&self, request: CreateGuestStarSessionRequest) -> Result<ApiResult<GuestStarSessionResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("channel:manage:guest_star")]), "guest_star/session", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait EndGuestStarSession {
+    /// Returns the session as it was when it ended.
+    async fn end_guest_star_session(&self, request: EndGuestStarSessionRequest) -> Result<ApiResult<GuestStarSessionResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl EndGuestStarSession for GuestStarGroup {
+    async fn end_guest_star_session(&self, request: EndGuestStarSessionRequest) -> Result<ApiResult<GuestStarSessionResponse>, Box<dyn Error>> {
+        if request.session_id.is_empty() {
+            return Err(String::from("Guest Star session ID is required!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::DELETE, Some(vec![String::from("channel:manage:guest_star")]), "guest_star/session", &request).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn session() {
+        let session: GuestStarSessionResponse = serde_json::from_value(json!({
+            "id": "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+            "guests": [
+                {
+                    "slot_id": "0",
+                    "is_live": true,
+                    "user_id": "9321049",
+                    "user_display_name": "Cool_User",
+                    "user_login": "cool_user",
+                    "volume": 100,
+                    "assigned_at": "2023-01-02T04:16:53.325Z",
+                    "audio_settings": {
+                        "is_available": true,
+                        "is_host_enabled": true,
+                        "is_guest_enabled": true
+                    },
+                    "video_settings": {
+                        "is_available": true,
+                        "is_host_enabled": true,
+                        "is_guest_enabled": false
+                    }
+                }
+            ]
+        })).unwrap();
+
+        assert_eq!(session.guests.len(), 1);
+        assert_eq!(session.guests[0].user_id, UserId::from("9321049"));
+        assert_eq!(session.guests[0].assigned_at.as_str(), "2023-01-02T04:16:53.325Z");
+        assert!(!session.guests[0].video_settings.is_guest_enabled);
+    }
+}
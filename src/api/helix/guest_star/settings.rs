@@ -0,0 +1,137 @@
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::GuestStarGroup;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum GroupLayout {
+    TiledLayout,
+    ScreenshareLayout,
+    HorizontalLayout,
+    VerticalLayout,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetChannelGuestStarSettingsRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId
+}
+
+impl GetChannelGuestStarSettingsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// The broadcaster or one of their moderators.
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelGuestStarSettingsResponse {
+    pub is_moderator_send_live_enabled: bool,
+    pub slot_count: u8,
+    pub is_browser_source_audio_enabled: bool,
+    pub group_layout: GroupLayout,
+    pub browser_source_token: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateChannelGuestStarSettingsBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_moderator_send_live_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slot_count: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_browser_source_audio_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group_layout: Option<GroupLayout>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    regenerate_browser_sources: Option<bool>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateChannelGuestStarSettingsRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    body: UpdateChannelGuestStarSettingsBody
+}
+
+impl UpdateChannelGuestStarSettingsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_send_live(mut self, is_moderator_send_live_enabled: bool) -> Self {
+        self.body.is_moderator_send_live_enabled = Some(is_moderator_send_live_enabled);
+        self
+    }
+
+    /// Number of guest slots, from 1 to 6.
+    pub fn with_slot_count(mut self, slot_count: u8) -> Self {
+        self.body.slot_count = Some(slot_count);
+        self
+    }
+
+    pub fn with_browser_source_audio(mut self, is_browser_source_audio_enabled: bool) -> Self {
+        self.body.is_browser_source_audio_enabled = Some(is_browser_source_audio_enabled);
+        self
+    }
+
+    pub fn with_group_layout(mut self, group_layout: GroupLayout) -> Self {
+        self.body.group_layout = Some(group_layout);
+        self
+    }
+
+    /// Invalidates every browser source URL handed out so far.
+    pub fn regenerate_browser_sources(mut self) -> Self {
+        self.body.regenerate_browser_sources = Some(true);
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetChannelGuestStarSettings {
+    async fn get_channel_guest_star_settings(&self, request: GetChannelGuestStarSettingsRequest) -> Result<ApiResult<ChannelGuestStarSettingsResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetChannelGuestStarSettings for GuestStarGroup {
+    async fn get_channel_guest_star_settings(&self, request: GetChannelGuestStarSettingsRequest) -> Result<ApiResult<ChannelGuestStarSettingsResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:guest_star")]), "guest_star/channel_settings", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UpdateChannelGuestStarSettings {
+    async fn update_channel_guest_star_settings(&self, request: UpdateChannelGuestStarSettingsRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UpdateChannelGuestStarSettings for GuestStarGroup {
+    async fn update_channel_guest_star_settings(&self, request: UpdateChannelGuestStarSettingsRequest) -> Result<(), Box<dyn Error>> {
+        if request.body.slot_count.is_some_and(|x| !(1..=6).contains(&x)) {
+            return Err(String::from("Guest Star slot count must be between 1 and 6!").into());
+        }
+
+        Ok(self.client.call_empty_with_body(ApiEndpoint::Helix, Method::PUT, Some(vec![String::from("channel:manage:guest_star")]), "guest_star/channel_settings", &request, &request.body).await?)
+    }
+}
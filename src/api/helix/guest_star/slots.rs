@@ -0,0 +1,274 @@
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::GuestStarGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct AssignGuestStarSlotRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+    session_id: String,
+    guest_id: UserId,
+    slot_id: String
+}
+
+impl AssignGuestStarSlotRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// The broadcaster or one of their moderators.
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_session_id<S: Into<String>>(mut self, session_id: S) -> Self {
+        self.session_id = session_id.into();
+        self
+    }
+
+    /// Must have a `READY` invite.
+    pub fn with_guest_id<I: Into<UserId>>(mut self, guest_id: I) -> Self {
+        self.guest_id = guest_id.into();
+        self
+    }
+
+    pub fn with_slot_id<S: Into<String>>(mut self, slot_id: S) -> Self {
+        self.slot_id = slot_id.into();
+        self
+    }
+}
+
+/// Moves a guest to another slot, swapping with whoever is in it.
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateGuestStarSlotRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+    session_id: String,
+    source_slot_id: String,
+    destination_slot_id: Option<String>
+}
+
+impl UpdateGuestStarSlotRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// The broadcaster or one of their moderators.
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_session_id<S: Into<String>>(mut self, session_id: S) -> Self {
+        self.session_id = session_id.into();
+        self
+    }
+
+    pub fn with_source_slot_id<S: Into<String>>(mut self, source_slot_id: S) -> Self {
+        self.source_slot_id = source_slot_id.into();
+        self
+    }
+
+    /// `None` moves the guest out of the session's slots.
+    pub fn with_destination_slot_id<S: Into<String>>(mut self, destination_slot_id: Option<S>) -> Self {
+        self.destination_slot_id = destination_slot_id.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct DeleteGuestStarSlotRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+    session_id: String,
+    guest_id: UserId,
+    slot_id: String,
+    should_reinvite_guest: Option<bool>
+}
+
+impl DeleteGuestStarSlotRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// The broadcaster or one of their moderators.
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_session_id<S: Into<String>>(mut self, session_id: S) -> Self {
+        self.session_id = session_id.into();
+        self
+    }
+
+    pub fn with_guest_id<I: Into<UserId>>(mut self, guest_id: I) -> Self {
+        self.guest_id = guest_id.into();
+        self
+    }
+
+    pub fn with_slot_id<S: Into<String>>(mut self, slot_id: S) -> Self {
+        self.slot_id = slot_id.into();
+        self
+    }
+
+    /// Sends the guest back to the backstage instead of removing their invite.
+    pub fn with_reinvite_guest(mut self, should_reinvite_guest: bool) -> Self {
+        self.should_reinvite_guest = Some(should_reinvite_guest);
+        self
+    }
+}
+
+/// Settings are only changed when they are set.
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateGuestStarSlotSettingsRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+    session_id: String,
+    slot_id: String,
+    is_audio_enabled: Option<bool>,
+    is_video_enabled: Option<bool>,
+    is_live: Option<bool>,
+    volume: Option<u8>
+}
+
+impl UpdateGuestStarSlotSettingsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// The broadcaster or one of their moderators.
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_session_id<S: Into<String>>(mut self, session_id: S) -> Self {
+        self.session_id = session_id.into();
+        self
+    }
+
+    pub fn with_slot_id<S: Into<String>>(mut self, slot_id: S) -> Self {
+        self.slot_id = slot_id.into();
+        self
+    }
+
+    pub fn with_audio(mut self, is_audio_enabled: bool) -> Self {
+        self.is_audio_enabled = Some(is_audio_enabled);
+        self
+    }
+
+    pub fn with_video(mut self, is_video_enabled: bool) -> Self {
+        self.is_video_enabled = Some(is_video_enabled);
+        self
+    }
+
+    pub fn with_live(mut self, is_live: bool) -> Self {
+        self.is_live = Some(is_live);
+        self
+    }
+
+    /// From 0 to 100.
+    pub fn with_volume(mut self, volume: u8) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+}
+
+fn required(session_id: &str, slot_id: &str) -> Result<(), Box<dyn Error>> {
+    if session_id.is_empty() || slot_id.is_empty() {
+        return Err(String::from("Guest Star session and slot IDs are required!").into());
+    }
+
+    Ok(())
+}
+
+#[async_trait(?Send)]
+pub trait AssignGuestStarSlot {
+    async fn assign_guest_star_slot(&self, request: AssignGuestStarSlotRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl AssignGuestStarSlot for GuestStarGroup {
+    async fn assign_guest_star_slot(&self, request: AssignGuestStarSlotRequest) -> Result<(), Box<dyn Error>> {
+        required(&request.session_id, &request.slot_id)?;
+
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("channel:manage:guest_star")]), "guest_star/slot", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UpdateGuestStarSlot {
+    async fn update_guest_star_slot(&self, request: UpdateGuestStarSlotRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UpdateGuestStarSlot for GuestStarGroup {
+    async fn update_guest_star_slot(&self, request: UpdateGuestStarSlotRequest) -> Result<(), Box<dyn Error>> {
+        required(&request.session_id, &request.source_slot_id)?;
+
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::PATCH, Some(vec![String::from("channel:manage:guest_star")]), "guest_star/slot", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait DeleteGuestStarSlot {
+    async fn delete_guest_star_slot(&self, request: DeleteGuestStarSlotRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl DeleteGuestStarSlot for GuestStarGroup {
+    async fn delete_guest_star_slot(&self, request: DeleteGuestStarSlotRequest) -> Result<(), Box<dyn Error>> {
+        required(&request.session_id, &request.slot_id)?;
+
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::DELETE, Some(vec![String::from("channel:manage:guest_star")]), "guest_star/slot", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UpdateGuestStarSlotSettings {
+    async fn update_guest_star_slot_settings(&self, request: UpdateGuestStarSlotSettingsRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UpdateGuestStarSlotSettings for GuestStarGroup {
+    async fn update_guest_star_slot_settings(&self, request: UpdateGuestStarSlotSettingsRequest) -> Result<(), Box<dyn Error>> {
+        required(&request.session_id, &request.slot_id)?;
+
+        if request.volume.is_some_and(|x| x > 100) {
+            return Err(String::from("Guest Star volume must be between 0 and 100!").into());
+        }
+
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::PATCH, Some(vec![String::from("channel:manage:guest_star")]), "guest_star/slot_settings", &request).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unset_settings_are_left_out() {
+        let request = UpdateGuestStarSlotSettingsRequest::default()
+            .with_broadcaster_id("9321049")
+            .with_moderator_id("9321049")
+            .with_session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+            .with_slot_id("1")
+            .with_volume(50);
+
+        assert_eq!(
+            serde_urlencoded::to_string(&request).unwrap(),
+            "broadcaster_id=9321049&moderator_id=9321049&session_id=2KFRQbFtpmfyD3IevNRnCzOPRJI&slot_id=1&volume=50"
+        );
+    }
+}
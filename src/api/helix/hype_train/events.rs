@@ -0,0 +1,102 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::HypeTrainGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetHypeTrainEventsRequest {
+    broadcaster_id: BroadcasterId,
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetHypeTrainEventsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum ContributionType {
+    Bits,
+    Subs,
+    Other,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HypeTrainContribution {
+    /// Bits, or subscription points where tier 1, 2 and 3 are worth 500, 1000 and 2500.
+    pub total: u64,
+    #[serde(rename = "type")]
+    pub kind: ContributionType,
+    pub user: UserId,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HypeTrainEventData {
+    pub broadcaster_id: BroadcasterId,
+    pub cooldown_end_time: Timestamp,
+    pub expires_at: Timestamp,
+    pub goal: u64,
+    pub id: String,
+    pub last_contribution: HypeTrainContribution,
+    pub level: u32,
+    pub started_at: Timestamp,
+    pub top_contributions: Vec<HypeTrainContribution>,
+    pub total: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HypeTrainEventResponse {
+    pub id: String,
+    pub event_type: String,
+    pub event_timestamp: Timestamp,
+    pub version: String,
+    pub event_data: HypeTrainEventData,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetHypeTrainEvents {
+    async fn get_hype_train_events(&self, request: GetHypeTrainEventsRequest) -> Result<ApiResult<HypeTrainEventResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetHypeTrainEvents for HypeTrainGroup {
+    async fn get_hype_train_events(&self, request: GetHypeTrainEventsRequest) -> Result<ApiResult<HypeTrainEventResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:hype_train")]), "hypetrain/events", &request).await?)
+    }
+}
@@ -0,0 +1,18 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod events;
+pub use events::*;
+
+pub struct HypeTrainGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl HypeTrainGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::{borrow::Borrow, fmt, ops::Deref};
+use serde::{Serialize, Deserialize, Deserializer};
+
+macro_rules! id {
+    ($(#[$meta:meta])* $name:ident, $borrowed:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        /// Borrowed counterpart, for passing an identifier around without cloning it.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+        #[serde(transparent)]
+        pub struct $borrowed<'a>(&'a str);
+
+        impl $name {
+            pub fn new<S: Into<String>>(id: S) -> Self {
+                Self(id.into())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn as_borrowed(&self) -> $borrowed<'_> {
+                $borrowed(&self.0)
+            }
+
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl<'a> $borrowed<'a> {
+            pub fn new(id: &'a str) -> Self {
+                Self(id)
+            }
+
+            pub fn as_str(&self) -> &'a str {
+                self.0
+            }
+
+            pub fn to_id(&self) -> $name {
+                $name(self.0.to_owned())
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl fmt::Display for $borrowed<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.0)
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<&String> for $name {
+            fn from(id: &String) -> Self {
+                Self(id.clone())
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self(id.to_owned())
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(id: &$name) -> Self {
+                id.clone()
+            }
+        }
+
+        impl From<$borrowed<'_>> for $name {
+            fn from(id: $borrowed<'_>) -> Self {
+                id.to_id()
+            }
+        }
+
+        impl<'a> From<&'a $name> for $borrowed<'a> {
+            fn from(id: &'a $name) -> Self {
+                id.as_borrowed()
+            }
+        }
+
+        impl<'a> From<&'a str> for $borrowed<'a> {
+            fn from(id: &'a str) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<$borrowed<'_>> for $name {
+            fn eq(&self, other: &$borrowed<'_>) -> bool {
+                self.0 == other.0
+            }
+        }
+    };
+}
+
+id!(
+    /// Identifies a user, see `BroadcasterId` for the user owning a channel.
+    UserId, UserIdRef
+);
+
+id!(
+    /// Identifies the user owning a channel. Every broadcaster is a user, convert explicitly with
+    /// `to_user_id` or `UserId::to_broadcaster_id` where an endpoint expects the other.
+    BroadcasterId, BroadcasterIdRef
+);
+
+id!(
+    /// Identifies a game or category.
+    GameId, GameIdRef
+);
+
+id!(ClipId, ClipIdRef);
+
+id!(VideoId, VideoIdRef);
+
+impl UserId {
+    pub fn to_broadcaster_id(&self) -> BroadcasterId {
+        BroadcasterId(self.0.clone())
+    }
+}
+
+impl BroadcasterId {
+    pub fn to_user_id(&self) -> UserId {
+        UserId(self.0.clone())
+    }
+}
+
+/// Deserializes an identifier that Twitch sends as an empty string or `null` when unset.
+pub fn deserialize_optional_id<'de, D: Deserializer<'de>, T: From<String>>(deserializer: D) -> Result<Option<T>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.filter(|x| !x.is_empty()).map(T::from))
+}
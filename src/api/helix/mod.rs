@@ -1,9 +1,75 @@
 
 pub mod result;
 pub mod pagination;
+pub mod amount;
+pub mod id;
+pub mod time;
 
 #[cfg(feature = "clips")]
 pub mod clips;
 
 #[cfg(feature = "search")]
 pub mod search;
+
+#[cfg(feature = "channels")]
+pub mod channels;
+
+#[cfg(feature = "moderation")]
+pub mod moderation;
+
+#[cfg(feature = "polls")]
+pub mod polls;
+
+#[cfg(feature = "predictions")]
+pub mod predictions;
+
+#[cfg(feature = "channel-points")]
+pub mod channel_points;
+
+#[cfg(feature = "subscriptions")]
+pub mod subscriptions;
+
+#[cfg(feature = "bits")]
+pub mod bits;
+
+#[cfg(feature = "chat")]
+pub mod chat;
+
+#[cfg(feature = "schedule")]
+pub mod schedule;
+
+#[cfg(feature = "raids")]
+pub mod raids;
+
+#[cfg(feature = "ads")]
+pub mod ads;
+
+#[cfg(feature = "hype-train")]
+pub mod hype_train;
+
+#[cfg(feature = "teams")]
+pub mod teams;
+
+#[cfg(feature = "goals")]
+pub mod goals;
+
+#[cfg(feature = "charity")]
+pub mod charity;
+
+#[cfg(feature = "guest-star")]
+pub mod guest_star;
+
+#[cfg(feature = "whispers")]
+pub mod whispers;
+
+#[cfg(feature = "extensions")]
+pub mod extensions;
+
+#[cfg(feature = "entitlements")]
+pub mod entitlements;
+
+#[cfg(feature = "analytics")]
+pub mod analytics;
+
+#[cfg(feature = "eventsub")]
+pub mod eventsub;
@@ -0,0 +1,256 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::ModerationGroup;
+
+#[derive(Debug, Serialize)]
+pub struct AutoModMessage {
+    msg_id: String,
+    msg_text: String
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CheckAutoModStatusBody {
+    data: Vec<AutoModMessage>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CheckAutoModStatusRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    body: CheckAutoModStatusBody
+}
+
+impl CheckAutoModStatusRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// `msg_id` is a caller-defined ID used to match the results to the messages.
+    pub fn with_message<S: Into<String>, T: Into<String>>(mut self, msg_id: S, msg_text: T) -> Self {
+        self.body.data.push(AutoModMessage {
+            msg_id: msg_id.into(),
+            msg_text: msg_text.into()
+        });
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutoModStatusResponse {
+    pub msg_id: String,
+    pub is_permitted: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum AutoModAction {
+    Allow,
+    Deny
+}
+
+#[derive(Debug, Serialize)]
+pub struct ManageHeldAutoModMessageBody {
+    user_id: UserId,
+    msg_id: String,
+    action: AutoModAction
+}
+
+impl Default for ManageHeldAutoModMessageBody {
+    fn default() -> Self {
+        Self {
+            user_id: UserId::default(),
+            msg_id: String::new(),
+            action: AutoModAction::Deny
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ManageHeldAutoModMessageRequest {
+    body: ManageHeldAutoModMessageBody
+}
+
+impl ManageHeldAutoModMessageRequest {
+    /// The moderator who is approving or denying the message.
+    pub fn with_user_id<I: Into<UserId>>(mut self, user_id: I) -> Self {
+        self.body.user_id = user_id.into();
+        self
+    }
+
+    pub fn with_msg_id<S: Into<String>>(mut self, msg_id: S) -> Self {
+        self.body.msg_id = msg_id.into();
+        self
+    }
+
+    pub fn with_action(mut self, action: AutoModAction) -> Self {
+        self.body.action = action;
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetAutoModSettingsRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId
+}
+
+impl GetAutoModSettingsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+}
+
+/// Every level ranges from 0 (no filtering) to 4 (most aggressive filtering).
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AutoModSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overall_level: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disability: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggression: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sexuality_sex_or_gender: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub misogyny: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bullying: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub swearing: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub race_ethnicity_or_religion: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sex_based_terms: Option<u8>
+}
+
+impl AutoModSettings {
+    fn levels(&self) -> [Option<u8>; 9] {
+        [
+            self.overall_level,
+            self.disability,
+            self.aggression,
+            self.sexuality_sex_or_gender,
+            self.misogyny,
+            self.bullying,
+            self.swearing,
+            self.race_ethnicity_or_religion,
+            self.sex_based_terms
+        ]
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutoModSettingsResponse {
+    pub broadcaster_id: BroadcasterId,
+    pub moderator_id: UserId,
+
+    #[serde(flatten)]
+    pub settings: AutoModSettings,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateAutoModSettingsRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+
+    #[serde(skip_serializing)]
+    body: AutoModSettings
+}
+
+impl UpdateAutoModSettingsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    /// Either `overall_level` or the individual levels may be set, but not both.
+    pub fn with_settings(mut self, settings: AutoModSettings) -> Self {
+        self.body = settings;
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait CheckAutoModStatus {
+    async fn check_automod_status(&self, request: CheckAutoModStatusRequest) -> Result<ApiResult<AutoModStatusResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl CheckAutoModStatus for ModerationGroup {
+    async fn check_automod_status(&self, request: CheckAutoModStatusRequest) -> Result<ApiResult<AutoModStatusResponse>, Box<dyn Error>> {
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("moderation:read")]), "moderation/enforcements/status", &request, &request.body).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait ManageHeldAutoModMessage {
+    async fn manage_held_automod_message(&self, request: ManageHeldAutoModMessageRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl ManageHeldAutoModMessage for ModerationGroup {
+    async fn manage_held_automod_message(&self, request: ManageHeldAutoModMessageRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty_with_body(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("moderator:manage:automod")]), "moderation/automod/message", &(), &request.body).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetAutoModSettings {
+    async fn get_automod_settings(&self, request: GetAutoModSettingsRequest) -> Result<ApiResult<AutoModSettingsResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetAutoModSettings for ModerationGroup {
+    async fn get_automod_settings(&self, request: GetAutoModSettingsRequest) -> Result<ApiResult<AutoModSettingsResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("moderator:read:automod_settings")]), "moderation/automod/settings", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UpdateAutoModSettings {
+    async fn update_automod_settings(&self, request: UpdateAutoModSettingsRequest) -> Result<ApiResult<AutoModSettingsResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UpdateAutoModSettings for ModerationGroup {
+    async fn update_automod_settings(&self, request: UpdateAutoModSettingsRequest) -> Result<ApiResult<AutoModSettingsResponse>, Box<dyn Error>> {
+        let levels = request.body.levels();
+
+        if levels.iter().flatten().any(|x| *x > 4) {
+            return Err(String::from("AutoMod levels must be between 0 and 4!").into());
+        }
+
+        if levels[0].is_some() && levels[1..].iter().any(|x| x.is_some()) {
+            return Err(String::from("Cannot set overall_level together with individual levels!").into());
+        }
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::PUT, Some(vec![String::from("moderator:manage:automod_settings")]), "moderation/automod/settings", &request, &request.body).await?)
+    }
+}
@@ -0,0 +1,206 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::{result::ApiResult, pagination::*}}, util::extend_url};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
+use super::ModerationGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct BanUserData {
+    user_id: UserId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct BanUserBody {
+    data: BanUserData
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct BanUserRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+
+    #[serde(skip_serializing)]
+    body: BanUserBody
+}
+
+impl BanUserRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_user_id<I: Into<UserId>>(mut self, user_id: I) -> Self {
+        self.body.data.user_id = user_id.into();
+        self
+    }
+
+    /// Timeout duration in seconds, `None` bans the user permanently.
+    pub fn with_duration(mut self, duration: Option<u32>) -> Self {
+        self.body.data.duration = duration;
+        self
+    }
+
+    pub fn with_reason<S: Into<String>>(mut self, reason: Option<S>) -> Self {
+        self.body.data.reason = reason.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BanUserResponse {
+    pub broadcaster_id: BroadcasterId,
+    pub moderator_id: UserId,
+    pub user_id: UserId,
+    pub created_at: Timestamp,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub end_time: Option<Timestamp>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UnbanUserRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+    user_id: UserId
+}
+
+impl UnbanUserRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_user_id<I: Into<UserId>>(mut self, user_id: I) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetBannedUsersRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    user_ids: Option<Vec<UserId>>,
+
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: Pagination
+}
+
+impl GetBannedUsersRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_user_ids<I: Into<UserId>>(mut self, user_ids: Vec<I>) -> Self {
+        self.user_ids = Some(user_ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.forward = Some(ForwardPagination {
+            after: after.map(|x| x.into())
+        });
+        self.pagination.backward = None;
+        self
+    }
+
+    pub fn with_before<S: Into<String>>(mut self, before: Option<S>) -> Self {
+        self.pagination.forward = None;
+        self.pagination.backward = Some(BackwardPagination {
+            before: before.map(|x| x.into())
+        });
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BannedUserResponse {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub expires_at: Option<Timestamp>,
+    pub created_at: Timestamp,
+    pub reason: String,
+    pub moderator_id: UserId,
+    pub moderator_login: String,
+    pub moderator_name: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait BanUser {
+    async fn ban_user(&self, request: BanUserRequest) -> Result<ApiResult<BanUserResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl BanUser for ModerationGroup {
+    async fn ban_user(&self, request: BanUserRequest) -> Result<ApiResult<BanUserResponse>, Box<dyn Error>> {
+        if request.body.data.duration.is_some_and(|x| x == 0 || x > 1_209_600) {
+            return Err(String::from("Timeout duration must be between 1 second and 2 weeks!").into());
+        }
+
+        if request.body.data.reason.as_ref().is_some_and(|x| x.chars().count() > 500) {
+            return Err(String::from("Ban reason cannot be longer than 500 characters!").into());
+        }
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("moderator:manage:banned_users")]), "moderation/bans", &request, &request.body).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UnbanUser {
+    async fn unban_user(&self, request: UnbanUserRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UnbanUser for ModerationGroup {
+    async fn unban_user(&self, request: UnbanUserRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::DELETE, Some(vec![String::from("moderator:manage:banned_users")]), "moderation/bans", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetBannedUsers {
+    async fn get_banned_users(&self, request: GetBannedUsersRequest) -> Result<ApiResult<BannedUserResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetBannedUsers for ModerationGroup {
+    async fn get_banned_users(&self, request: GetBannedUsersRequest) -> Result<ApiResult<BannedUserResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("moderation:read")]), extend_url("moderation/banned", "user_id", &request.user_ids)?, &request).await?)
+    }
+}
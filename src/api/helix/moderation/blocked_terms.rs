@@ -0,0 +1,155 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
+use super::ModerationGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetBlockedTermsRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetBlockedTermsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct AddBlockedTermBody {
+    text: String
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct AddBlockedTermRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+
+    #[serde(skip_serializing)]
+    body: AddBlockedTermBody
+}
+
+impl AddBlockedTermRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    /// May contain `*` as a wildcard.
+    pub fn with_text<S: Into<String>>(mut self, text: S) -> Self {
+        self.body.text = text.into();
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct RemoveBlockedTermRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+    id: String
+}
+
+impl RemoveBlockedTermRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BlockedTermResponse {
+    pub broadcaster_id: BroadcasterId,
+    pub moderator_id: UserId,
+    pub id: String,
+    pub text: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub expires_at: Option<Timestamp>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetBlockedTerms {
+    async fn get_blocked_terms(&self, request: GetBlockedTermsRequest) -> Result<ApiResult<BlockedTermResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetBlockedTerms for ModerationGroup {
+    async fn get_blocked_terms(&self, request: GetBlockedTermsRequest) -> Result<ApiResult<BlockedTermResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("moderator:read:blocked_terms")]), "moderation/blocked_terms", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait AddBlockedTerm {
+    async fn add_blocked_term(&self, request: AddBlockedTermRequest) -> Result<ApiResult<BlockedTermResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl AddBlockedTerm for ModerationGroup {
+    async fn add_blocked_term(&self, request: AddBlockedTermRequest) -> Result<ApiResult<BlockedTermResponse>, Box<dyn Error>> {
+        let length = request.body.text.chars().count();
+        if !(2..=500).contains(&length) {
+            return Err(String::from("Blocked term must be between 2 and 500 characters!").into());
+        }
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("moderator:manage:blocked_terms")]), "moderation/blocked_terms", &request, &request.body).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait RemoveBlockedTerm {
+    async fn remove_blocked_term(&self, request: RemoveBlockedTermRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl RemoveBlockedTerm for ModerationGroup {
+    async fn remove_blocked_term(&self, request: RemoveBlockedTermRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::DELETE, Some(vec![String::from("moderator:manage:blocked_terms")]), "moderation/blocked_terms", &request).await?)
+    }
+}
@@ -0,0 +1,46 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::ModerationGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct DeleteChatMessagesRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+    message_id: Option<String>
+}
+
+impl DeleteChatMessagesRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    /// `None` clears every message in the chat room.
+    pub fn with_message_id<S: Into<String>>(mut self, message_id: Option<S>) -> Self {
+        self.message_id = message_id.map(|x| x.into());
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait DeleteChatMessages {
+    async fn delete_chat_messages(&self, request: DeleteChatMessagesRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl DeleteChatMessages for ModerationGroup {
+    async fn delete_chat_messages(&self, request: DeleteChatMessagesRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::DELETE, Some(vec![String::from("moderator:manage:chat_messages")]), "moderation/chat", &request).await?)
+    }
+}
@@ -0,0 +1,36 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod bans;
+pub use bans::*;
+
+mod moderators;
+pub use moderators::*;
+
+mod vips;
+pub use vips::*;
+
+mod blocked_terms;
+pub use blocked_terms::*;
+
+mod automod;
+pub use automod::*;
+
+mod chat;
+pub use chat::*;
+
+mod shield_mode;
+pub use shield_mode::*;
+
+pub struct ModerationGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl ModerationGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
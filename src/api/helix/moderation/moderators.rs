@@ -0,0 +1,109 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}}, util::extend_url};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::ModerationGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetModeratorsRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    user_ids: Option<Vec<UserId>>,
+
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetModeratorsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_user_ids<I: Into<UserId>>(mut self, user_ids: Vec<I>) -> Self {
+        self.user_ids = Some(user_ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModeratorResponse {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ModeratorRequest {
+    broadcaster_id: BroadcasterId,
+    user_id: UserId
+}
+
+impl ModeratorRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_user_id<I: Into<UserId>>(mut self, user_id: I) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetModerators {
+    async fn get_moderators(&self, request: GetModeratorsRequest) -> Result<ApiResult<ModeratorResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetModerators for ModerationGroup {
+    async fn get_moderators(&self, request: GetModeratorsRequest) -> Result<ApiResult<ModeratorResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("moderation:read")]), extend_url("moderation/moderators", "user_id", &request.user_ids)?, &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait AddModerator {
+    async fn add_moderator(&self, request: ModeratorRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl AddModerator for ModerationGroup {
+    async fn add_moderator(&self, request: ModeratorRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("channel:manage:moderators")]), "moderation/moderators", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait RemoveModerator {
+    async fn remove_moderator(&self, request: ModeratorRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl RemoveModerator for ModerationGroup {
+    async fn remove_moderator(&self, request: ModeratorRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::DELETE, Some(vec![String::from("channel:manage:moderators")]), "moderation/moderators", &request).await?)
+    }
+}
@@ -0,0 +1,97 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
+use super::ModerationGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetShieldModeStatusRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId
+}
+
+impl GetShieldModeStatusRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateShieldModeStatusBody {
+    is_active: bool
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateShieldModeStatusRequest {
+    broadcaster_id: BroadcasterId,
+    moderator_id: UserId,
+
+    #[serde(skip_serializing)]
+    body: UpdateShieldModeStatusBody
+}
+
+impl UpdateShieldModeStatusRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_moderator_id<I: Into<UserId>>(mut self, moderator_id: I) -> Self {
+        self.moderator_id = moderator_id.into();
+        self
+    }
+
+    pub fn with_active(mut self, is_active: bool) -> Self {
+        self.body.is_active = is_active;
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShieldModeStatusResponse {
+    pub is_active: bool,
+    pub moderator_id: UserId,
+    pub moderator_login: String,
+    pub moderator_name: String,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub last_activated_at: Option<Timestamp>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetShieldModeStatus {
+    async fn get_shield_mode_status(&self, request: GetShieldModeStatusRequest) -> Result<ApiResult<ShieldModeStatusResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetShieldModeStatus for ModerationGroup {
+    async fn get_shield_mode_status(&self, request: GetShieldModeStatusRequest) -> Result<ApiResult<ShieldModeStatusResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("moderator:read:shield_mode")]), "moderation/shield_mode", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UpdateShieldModeStatus {
+    async fn update_shield_mode_status(&self, request: UpdateShieldModeStatusRequest) -> Result<ApiResult<ShieldModeStatusResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UpdateShieldModeStatus for ModerationGroup {
+    async fn update_shield_mode_status(&self, request: UpdateShieldModeStatusRequest) -> Result<ApiResult<ShieldModeStatusResponse>, Box<dyn Error>> {
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::PUT, Some(vec![String::from("moderator:manage:shield_mode")]), "moderation/shield_mode", &request, &request.body).await?)
+    }
+}
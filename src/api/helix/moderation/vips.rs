@@ -0,0 +1,109 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}}, util::extend_url};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::ModerationGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetVipsRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    user_ids: Option<Vec<UserId>>,
+
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetVipsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_user_ids<I: Into<UserId>>(mut self, user_ids: Vec<I>) -> Self {
+        self.user_ids = Some(user_ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VipResponse {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct VipRequest {
+    broadcaster_id: BroadcasterId,
+    user_id: UserId
+}
+
+impl VipRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_user_id<I: Into<UserId>>(mut self, user_id: I) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetVips {
+    async fn get_vips(&self, request: GetVipsRequest) -> Result<ApiResult<VipResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetVips for ModerationGroup {
+    async fn get_vips(&self, request: GetVipsRequest) -> Result<ApiResult<VipResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:vips")]), extend_url("channels/vips", "user_id", &request.user_ids)?, &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait AddVip {
+    async fn add_vip(&self, request: VipRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl AddVip for ModerationGroup {
+    async fn add_vip(&self, request: VipRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("channel:manage:vips")]), "channels/vips", &request).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait RemoveVip {
+    async fn remove_vip(&self, request: VipRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl RemoveVip for ModerationGroup {
+    async fn remove_vip(&self, request: VipRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::DELETE, Some(vec![String::from("channel:manage:vips")]), "channels/vips", &request).await?)
+    }
+}
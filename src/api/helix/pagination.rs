@@ -3,16 +3,19 @@ use std::future::Future;
 use serde::{Serialize, Deserialize};
 use super::result::ApiResult;
 
+#[allow(dead_code)]
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub(crate) struct ForwardPagination {
     pub(crate) after: Option<String>
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub(crate) struct BackwardPagination {
     pub(crate) before: Option<String>
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub(crate) struct Pagination {
     #[serde(flatten)]
@@ -21,10 +24,10 @@ pub(crate) struct Pagination {
     pub(crate) backward: Option<BackwardPagination>
 }
 
-pub async fn get_all<T, F, R>(grabber: F, limit: Option<usize>) -> Result<Vec<T>, Box<dyn Error>>
+pub async fn get_all<T, E, F, R>(grabber: F, limit: Option<usize>) -> Result<Vec<T>, Box<dyn Error>>
 where
     F: Fn(Option<String>) -> R,
-    R: Future<Output = Result<ApiResult<T>, Box<dyn Error>>>
+    R: Future<Output = Result<ApiResult<T, E>, Box<dyn Error>>>
 {
     let mut list = vec![];
     let mut current_pagination = None;
@@ -37,7 +40,7 @@ where
             current_pagination = pagination.cursor.clone();
         }
 
-        if current_pagination.is_none() || limit.map_or(false, |x| x < list.len()) {
+        if current_pagination.is_none() || limit.is_some_and(|x| x < list.len()) {
             break;
         }
     }
@@ -0,0 +1,96 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use super::{PollsGroup, PollResponse};
+
+#[derive(Debug, Serialize)]
+pub struct CreatePollChoice {
+    title: String
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CreatePollRequest {
+    broadcaster_id: BroadcasterId,
+    title: String,
+    choices: Vec<CreatePollChoice>,
+    duration: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_points_voting_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_points_per_vote: Option<u32>
+}
+
+impl CreatePollRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_choice<S: Into<String>>(mut self, title: S) -> Self {
+        self.choices.push(CreatePollChoice {
+            title: title.into()
+        });
+        self
+    }
+
+    /// Duration in seconds, from 15 to 1800.
+    pub fn with_duration(mut self, duration: u32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Allows viewers to cast additional votes for `channel_points_per_vote` channel points each.
+    pub fn with_channel_points_per_vote(mut self, channel_points_per_vote: Option<u32>) -> Self {
+        self.channel_points_voting_enabled = Some(channel_points_per_vote.is_some());
+        self.channel_points_per_vote = channel_points_per_vote;
+        self
+    }
+
+    fn validate(&self) -> Result<(), Box<dyn Error>> {
+        if self.title.is_empty() || self.title.chars().count() > 60 {
+            return Err(String::from("Poll title must be between 1 and 60 characters!").into());
+        }
+
+        if !(2..=5).contains(&self.choices.len()) {
+            return Err(String::from("Poll must have between 2 and 5 choices!").into());
+        }
+
+        if self.choices.iter().any(|x| x.title.is_empty() || x.title.chars().count() > 25) {
+            return Err(String::from("Poll choice title must be between 1 and 25 characters!").into());
+        }
+
+        if !(15..=1800).contains(&self.duration) {
+            return Err(String::from("Poll duration must be between 15 and 1800 seconds!").into());
+        }
+
+        if self.channel_points_per_vote.is_some_and(|x| !(1..=1_000_000).contains(&x)) {
+            return Err(String::from("Channel points per vote must be between 1 and 1000000!").into());
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+pub trait CreatePoll {
+    async fn create_poll(&self, request: CreatePollRequest) -> Result<ApiResult<PollResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl CreatePoll for PollsGroup {
+    async fn create_poll(&self, request: CreatePollRequest) -> Result<ApiResult<PollResponse>, Box<dyn Error>> {
+        request.validate()?;
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("channel:manage:polls")]), "polls", &(), &request).await?)
+    }
+}
@@ -0,0 +1,64 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use super::{PollsGroup, PollResponse, PollStatus};
+
+#[derive(Debug, Serialize)]
+pub struct EndPollRequest {
+    broadcaster_id: BroadcasterId,
+    id: String,
+    status: PollStatus
+}
+
+impl Default for EndPollRequest {
+    fn default() -> Self {
+        Self {
+            broadcaster_id: BroadcasterId::default(),
+            id: String::new(),
+            status: PollStatus::Terminated
+        }
+    }
+}
+
+impl EndPollRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Either `Terminated` (results stay visible) or `Archived` (results are hidden).
+    pub fn with_status(mut self, status: PollStatus) -> Self {
+        self.status = status;
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait EndPoll {
+    async fn end_poll(&self, request: EndPollRequest) -> Result<ApiResult<PollResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl EndPoll for PollsGroup {
+    async fn end_poll(&self, request: EndPollRequest) -> Result<ApiResult<PollResponse>, Box<dyn Error>> {
+        if request.broadcaster_id.is_empty() || request.id.is_empty() {
+            return Err(String::from("Broadcaster and poll IDs are required!").into());
+        }
+
+        if request.status != PollStatus::Terminated && request.status != PollStatus::Archived {
+            return Err(String::from("Poll can only be ended as TERMINATED or ARCHIVED!").into());
+        }
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::PATCH, Some(vec![String::from("channel:manage:polls")]), "polls", &(), &request).await?)
+    }
+}
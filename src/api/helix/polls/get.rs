@@ -0,0 +1,60 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}}, util::extend_url};
+use crate::api::helix::id::BroadcasterId;
+use super::{PollsGroup, PollResponse};
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetPollsRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    ids: Option<Vec<String>>,
+
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetPollsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_ids<S: Into<String>>(mut self, ids: Vec<S>) -> Self {
+        self.ids = Some(ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetPolls {
+    async fn get_polls(&self, request: GetPollsRequest) -> Result<ApiResult<PollResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetPolls for PollsGroup {
+    async fn get_polls(&self, request: GetPollsRequest) -> Result<ApiResult<PollResponse>, Box<dyn Error>> {
+        if request.ids.as_ref().map_or(0, |x| x.len()) > 20 {
+            return Err(String::from("Cannot request more than 20 polls at once!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:polls")]), extend_url("polls", "id", &request.ids)?, &request).await?)
+    }
+}
@@ -0,0 +1,27 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod poll;
+pub use poll::*;
+
+mod get;
+pub use get::*;
+
+mod create;
+pub use create::*;
+
+mod end;
+pub use end::*;
+
+pub struct PollsGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl PollsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
@@ -0,0 +1,55 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum PollStatus {
+    Active,
+    Completed,
+    Terminated,
+    Archived,
+    Moderated,
+    Invalid,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PollChoice {
+    pub id: String,
+    pub title: String,
+    pub votes: u64,
+    pub channel_points_votes: u64,
+    pub bits_votes: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PollResponse {
+    pub id: String,
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_name: String,
+    pub broadcaster_login: String,
+    pub title: String,
+    pub choices: Vec<PollChoice>,
+    pub bits_voting_enabled: bool,
+    pub bits_per_vote: u64,
+    pub channel_points_voting_enabled: bool,
+    pub channel_points_per_vote: u64,
+    pub status: PollStatus,
+    pub duration: u32,
+    pub started_at: Timestamp,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub ended_at: Option<Timestamp>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
@@ -0,0 +1,81 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use super::{PredictionsGroup, PredictionResponse};
+
+#[derive(Debug, Serialize)]
+pub struct CreatePredictionOutcome {
+    title: String
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CreatePredictionRequest {
+    broadcaster_id: BroadcasterId,
+    title: String,
+    outcomes: Vec<CreatePredictionOutcome>,
+    prediction_window: u32
+}
+
+impl CreatePredictionRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_outcome<S: Into<String>>(mut self, title: S) -> Self {
+        self.outcomes.push(CreatePredictionOutcome {
+            title: title.into()
+        });
+        self
+    }
+
+    /// Seconds viewers have to make a prediction, from 30 to 1800.
+    pub fn with_prediction_window(mut self, prediction_window: u32) -> Self {
+        self.prediction_window = prediction_window;
+        self
+    }
+
+    fn validate(&self) -> Result<(), Box<dyn Error>> {
+        if self.title.is_empty() || self.title.chars().count() > 45 {
+            return Err(String::from("Prediction title must be between 1 and 45 characters!").into());
+        }
+
+        if !(2..=10).contains(&self.outcomes.len()) {
+            return Err(String::from("Prediction must have between 2 and 10 outcomes!").into());
+        }
+
+        if self.outcomes.iter().any(|x| x.title.is_empty() || x.title.chars().count() > 25) {
+            return Err(String::from("Prediction outcome title must be between 1 and 25 characters!").into());
+        }
+
+        if !(30..=1800).contains(&self.prediction_window) {
+            return Err(String::from("Prediction window must be between 30 and 1800 seconds!").into());
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+pub trait CreatePrediction {
+    async fn create_prediction(&self, request: CreatePredictionRequest) -> Result<ApiResult<PredictionResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl CreatePrediction for PredictionsGroup {
+    async fn create_prediction(&self, request: CreatePredictionRequest) -> Result<ApiResult<PredictionResponse>, Box<dyn Error>> {
+        request.validate()?;
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("channel:manage:predictions")]), "predictions", &(), &request).await?)
+    }
+}
@@ -0,0 +1,115 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use super::{PredictionsGroup, PredictionResponse, PredictionStatus};
+
+/// Set the outcome with `lock`, `resolve` or `cancel`, nothing is sent without one.
+#[derive(Debug, Default, Serialize)]
+pub struct EndPredictionRequest {
+    broadcaster_id: BroadcasterId,
+    id: String,
+    status: Option<PredictionStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    winning_outcome_id: Option<String>
+}
+
+impl EndPredictionRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Stops accepting predictions, the prediction can still be resolved or canceled afterwards.
+    pub fn lock(mut self) -> Self {
+        self.status = Some(PredictionStatus::Locked);
+        self.winning_outcome_id = None;
+        self
+    }
+
+    /// Pays out the channel points of everyone who picked `winning_outcome_id`.
+    pub fn resolve<S: Into<String>>(mut self, winning_outcome_id: S) -> Self {
+        self.status = Some(PredictionStatus::Resolved);
+        self.winning_outcome_id = Some(winning_outcome_id.into());
+        self
+    }
+
+    /// Refunds every channel point that was wagered.
+    pub fn cancel(mut self) -> Self {
+        self.status = Some(PredictionStatus::Canceled);
+        self.winning_outcome_id = None;
+        self
+    }
+
+    fn validate(&self) -> Result<(), Box<dyn Error>> {
+        if self.broadcaster_id.is_empty() || self.id.is_empty() {
+            return Err(String::from("Broadcaster and prediction IDs are required!").into());
+        }
+
+        if self.status.is_none() {
+            return Err(String::from("Prediction must be locked, resolved or canceled!").into());
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+pub trait EndPrediction {
+    async fn end_prediction(&self, request: EndPredictionRequest) -> Result<ApiResult<PredictionResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl EndPrediction for PredictionsGroup {
+    async fn end_prediction(&self, request: EndPredictionRequest) -> Result<ApiResult<PredictionResponse>, Box<dyn Error>> {
+        request.validate()?;
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::PATCH, Some(vec![String::from("channel:manage:predictions")]), "predictions", &(), &request).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    fn request() -> EndPredictionRequest {
+        EndPredictionRequest::default()
+            .with_broadcaster_id("141981764")
+            .with_id("bc637af0-7766-4525-9308-4112f4cbf178")
+    }
+
+    #[test]
+    fn requires_status() {
+        assert!(request().validate().is_err());
+        assert!(request().lock().validate().is_ok());
+        assert!(request().cancel().validate().is_ok());
+    }
+
+    #[test]
+    fn requires_ids() {
+        assert!(EndPredictionRequest::default().cancel().validate().is_err());
+        assert!(EndPredictionRequest::default().with_id("bc637af0-7766-4525-9308-4112f4cbf178").cancel().validate().is_err());
+        assert!(EndPredictionRequest::default().with_broadcaster_id("141981764").cancel().validate().is_err());
+    }
+
+    #[test]
+    fn body() {
+        let request = request().lock().resolve("73085848-a94d-4040-9d21-2cb7a89374b7");
+        assert_eq!(serde_json::to_value(&request).unwrap(), json!({
+            "broadcaster_id": "141981764",
+            "id": "bc637af0-7766-4525-9308-4112f4cbf178",
+            "status": "RESOLVED",
+            "winning_outcome_id": "73085848-a94d-4040-9d21-2cb7a89374b7"
+        }));
+    }
+}
@@ -0,0 +1,60 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}}, util::extend_url};
+use crate::api::helix::id::BroadcasterId;
+use super::{PredictionsGroup, PredictionResponse};
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetPredictionsRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    ids: Option<Vec<String>>,
+
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetPredictionsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_ids<S: Into<String>>(mut self, ids: Vec<S>) -> Self {
+        self.ids = Some(ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetPredictions {
+    async fn get_predictions(&self, request: GetPredictionsRequest) -> Result<ApiResult<PredictionResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetPredictions for PredictionsGroup {
+    async fn get_predictions(&self, request: GetPredictionsRequest) -> Result<ApiResult<PredictionResponse>, Box<dyn Error>> {
+        if request.ids.as_ref().map_or(0, |x| x.len()) > 25 {
+            return Err(String::from("Cannot request more than 25 predictions at once!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:predictions")]), extend_url("predictions", "id", &request.ids)?, &request).await?)
+    }
+}
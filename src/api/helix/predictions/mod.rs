@@ -0,0 +1,27 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod prediction;
+pub use prediction::*;
+
+mod get;
+pub use get::*;
+
+mod create;
+pub use create::*;
+
+mod end;
+pub use end::*;
+
+pub struct PredictionsGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl PredictionsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
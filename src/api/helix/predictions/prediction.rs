@@ -0,0 +1,76 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum PredictionStatus {
+    Active,
+    Canceled,
+    Locked,
+    Resolved,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum OutcomeColor {
+    Blue,
+    Pink,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TopPredictor {
+    pub user_id: UserId,
+    pub user_name: String,
+    pub user_login: String,
+    pub channel_points_used: u64,
+    pub channel_points_won: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PredictionOutcome {
+    pub id: String,
+    pub title: String,
+    pub users: u64,
+    pub channel_points: u64,
+    pub top_predictors: Option<Vec<TopPredictor>>,
+    pub color: OutcomeColor,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PredictionResponse {
+    pub id: String,
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_name: String,
+    pub broadcaster_login: String,
+    pub title: String,
+    pub winning_outcome_id: Option<String>,
+    pub outcomes: Vec<PredictionOutcome>,
+    pub prediction_window: u32,
+    pub status: PredictionStatus,
+    pub created_at: Timestamp,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub ended_at: Option<Timestamp>,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub locked_at: Option<Timestamp>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
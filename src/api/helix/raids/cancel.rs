@@ -0,0 +1,33 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::BroadcasterId;
+use super::RaidsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct CancelRaidRequest {
+    broadcaster_id: BroadcasterId
+}
+
+impl CancelRaidRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait CancelRaid {
+    async fn cancel_raid(&self, request: CancelRaidRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl CancelRaid for RaidsGroup {
+    async fn cancel_raid(&self, request: CancelRaidRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::DELETE, Some(vec![String::from("channel:manage:raids")]), "raids", &request).await?)
+    }
+}
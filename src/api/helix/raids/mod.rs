@@ -0,0 +1,21 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod start;
+pub use start::*;
+
+mod cancel;
+pub use cancel::*;
+
+pub struct RaidsGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl RaidsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
@@ -0,0 +1,54 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::Timestamp;
+use super::RaidsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct StartRaidRequest {
+    from_broadcaster_id: BroadcasterId,
+    to_broadcaster_id: BroadcasterId
+}
+
+impl StartRaidRequest {
+    pub fn with_from_broadcaster_id<I: Into<BroadcasterId>>(mut self, from_broadcaster_id: I) -> Self {
+        self.from_broadcaster_id = from_broadcaster_id.into();
+        self
+    }
+
+    pub fn with_to_broadcaster_id<I: Into<BroadcasterId>>(mut self, to_broadcaster_id: I) -> Self {
+        self.to_broadcaster_id = to_broadcaster_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StartRaidResponse {
+    pub created_at: Timestamp,
+    pub is_mature: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait StartRaid {
+    async fn start_raid(&self, request: StartRaidRequest) -> Result<ApiResult<StartRaidResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl StartRaid for RaidsGroup {
+    async fn start_raid(&self, request: StartRaidRequest) -> Result<ApiResult<StartRaidResponse>, Box<dyn Error>> {
+        if request.from_broadcaster_id == request.to_broadcaster_id {
+            return Err(String::from("Broadcasters cannot raid themselves!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("channel:manage:raids")]), "raids", &request).await?)
+    }
+}
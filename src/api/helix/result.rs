@@ -4,15 +4,41 @@ use serde::{Serialize, Deserialize};
 use serde_json::Value;
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(from = "PaginationRepr")]
 pub struct Pagination {
     pub cursor: Option<String>
 }
 
+/// Most endpoints wrap the cursor in an object, a few return it as a bare string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PaginationRepr {
+    Object {
+        cursor: Option<String>
+    },
+    Cursor(String)
+}
+
+impl From<PaginationRepr> for Pagination {
+    fn from(repr: PaginationRepr) -> Self {
+        let cursor = match repr {
+            PaginationRepr::Object { cursor } => cursor,
+            PaginationRepr::Cursor(cursor) => Some(cursor)
+        };
+
+        Self {
+            cursor: cursor.filter(|x| !x.is_empty())
+        }
+    }
+}
+
+/// `E` holds the top-level fields besides `data` and `pagination`, endpoints with known extra
+/// fields use a typed struct while everything else falls back to a map.
 #[derive(Debug, Serialize, Deserialize)]
-pub struct ApiResult<T> {
+pub struct ApiResult<T, E = HashMap<String, Value>> {
     pub data: Vec<T>,
 
     pub pagination: Option<Pagination>,
     #[serde(flatten)]
-    pub extra: HashMap<String, Value>
+    pub extra: E
 }
@@ -0,0 +1,72 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::pagination::ForwardPagination}, util::extend_url};
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::Timestamp;
+use super::{ScheduleGroup, ScheduleResult};
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetChannelStreamScheduleRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    ids: Option<Vec<String>>,
+
+    start_time: Option<Timestamp>,
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: ForwardPagination
+}
+
+impl GetChannelStreamScheduleRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_ids<S: Into<String>>(mut self, ids: Vec<S>) -> Self {
+        self.ids = Some(ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// RFC3339 timestamp of the earliest segment to return.
+    pub fn with_start_time<T: Into<Timestamp>>(mut self, start_time: Option<T>) -> Self {
+        self.start_time = start_time.map(|x| x.into());
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.after = after.map(|x| x.into());
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetChannelStreamSchedule {
+    async fn get_channel_stream_schedule(&self, request: GetChannelStreamScheduleRequest) -> Result<ScheduleResult, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetChannelStreamSchedule for ScheduleGroup {
+    async fn get_channel_stream_schedule(&self, request: GetChannelStreamScheduleRequest) -> Result<ScheduleResult, Box<dyn Error>> {
+        if request.ids.as_ref().map_or(0, |x| x.len()) > 100 {
+            return Err(String::from("Cannot request more than 100 segments at once!").into());
+        }
+
+        if request.first.is_some_and(|x| !(1..=25).contains(&x)) {
+            return Err(String::from("Schedule page size must be between 1 and 25!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, extend_url("schedule", "id", &request.ids)?, &request).await?)
+    }
+}
@@ -0,0 +1,34 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::BroadcasterId;
+use super::ScheduleGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetChannelICalendarRequest {
+    broadcaster_id: BroadcasterId
+}
+
+impl GetChannelICalendarRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait GetChannelICalendar {
+    /// Returns the raw `text/calendar` body.
+    async fn get_channel_icalendar(&self, request: GetChannelICalendarRequest) -> Result<String, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetChannelICalendar for ScheduleGroup {
+    async fn get_channel_icalendar(&self, request: GetChannelICalendarRequest) -> Result<String, Box<dyn Error>> {
+        Ok(self.client.call_text(ApiEndpoint::Helix, Method::GET, None, "schedule/icalendar", &request, "text/calendar").await?)
+    }
+}
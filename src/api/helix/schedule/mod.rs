@@ -0,0 +1,30 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod stream_schedule;
+pub use stream_schedule::*;
+
+mod get;
+pub use get::*;
+
+mod settings;
+pub use settings::*;
+
+mod segment;
+pub use segment::*;
+
+mod icalendar;
+pub use icalendar::*;
+
+pub struct ScheduleGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl ScheduleGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
@@ -0,0 +1,204 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::Timestamp;
+use super::{ScheduleGroup, ScheduleResult};
+
+#[derive(Debug, Default, Serialize)]
+pub struct ScheduleSegmentBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_time: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_recurring: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_canceled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>
+}
+
+impl ScheduleSegmentBody {
+    fn validate(&self) -> Result<(), Box<dyn Error>> {
+        if self.duration.is_some_and(|x| !(30..=1380).contains(&x)) {
+            return Err(String::from("Segment duration must be between 30 and 1380 minutes!").into());
+        }
+
+        if self.title.as_ref().is_some_and(|x| x.chars().count() > 140) {
+            return Err(String::from("Segment title cannot be longer than 140 characters!").into());
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CreateScheduleSegmentRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    body: ScheduleSegmentBody
+}
+
+impl CreateScheduleSegmentRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// RFC3339 timestamp of when the segment starts.
+    pub fn with_start_time<T: Into<Timestamp>>(mut self, start_time: T) -> Self {
+        self.body.start_time = Some(start_time.into());
+        self
+    }
+
+    /// IANA time zone, for example `America/New_York`.
+    pub fn with_timezone<S: Into<String>>(mut self, timezone: S) -> Self {
+        self.body.timezone = Some(timezone.into());
+        self
+    }
+
+    /// Duration in minutes, from 30 to 1380.
+    pub fn with_duration(mut self, duration: Option<u32>) -> Self {
+        self.body.duration = duration;
+        self
+    }
+
+    pub fn with_recurring(mut self, is_recurring: bool) -> Self {
+        self.body.is_recurring = Some(is_recurring);
+        self
+    }
+
+    pub fn with_category_id<S: Into<String>>(mut self, category_id: Option<S>) -> Self {
+        self.body.category_id = category_id.map(|x| x.into());
+        self
+    }
+
+    pub fn with_title<S: Into<String>>(mut self, title: Option<S>) -> Self {
+        self.body.title = title.map(|x| x.into());
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateScheduleSegmentRequest {
+    broadcaster_id: BroadcasterId,
+    id: String,
+
+    #[serde(skip_serializing)]
+    body: ScheduleSegmentBody
+}
+
+impl UpdateScheduleSegmentRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn with_start_time<T: Into<Timestamp>>(mut self, start_time: T) -> Self {
+        self.body.start_time = Some(start_time.into());
+        self
+    }
+
+    pub fn with_timezone<S: Into<String>>(mut self, timezone: S) -> Self {
+        self.body.timezone = Some(timezone.into());
+        self
+    }
+
+    pub fn with_duration(mut self, duration: u32) -> Self {
+        self.body.duration = Some(duration);
+        self
+    }
+
+    /// Canceling a recurring segment only cancels its next occurrence.
+    pub fn with_canceled(mut self, is_canceled: bool) -> Self {
+        self.body.is_canceled = Some(is_canceled);
+        self
+    }
+
+    pub fn with_category_id<S: Into<String>>(mut self, category_id: S) -> Self {
+        self.body.category_id = Some(category_id.into());
+        self
+    }
+
+    pub fn with_title<S: Into<String>>(mut self, title: S) -> Self {
+        self.body.title = Some(title.into());
+        self
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct DeleteScheduleSegmentRequest {
+    broadcaster_id: BroadcasterId,
+    id: String
+}
+
+impl DeleteScheduleSegmentRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait CreateScheduleSegment {
+    async fn create_schedule_segment(&self, request: CreateScheduleSegmentRequest) -> Result<ScheduleResult, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl CreateScheduleSegment for ScheduleGroup {
+    async fn create_schedule_segment(&self, request: CreateScheduleSegmentRequest) -> Result<ScheduleResult, Box<dyn Error>> {
+        if request.body.start_time.is_none() || request.body.timezone.is_none() {
+            return Err(String::from("Segment start time and time zone are required!").into());
+        }
+
+        request.body.validate()?;
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("channel:manage:schedule")]), "schedule/segment", &request, &request.body).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UpdateScheduleSegment {
+    async fn update_schedule_segment(&self, request: UpdateScheduleSegmentRequest) -> Result<ScheduleResult, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UpdateScheduleSegment for ScheduleGroup {
+    async fn update_schedule_segment(&self, request: UpdateScheduleSegmentRequest) -> Result<ScheduleResult, Box<dyn Error>> {
+        request.body.validate()?;
+
+        Ok(self.client.call_with_body(ApiEndpoint::Helix, Method::PATCH, Some(vec![String::from("channel:manage:schedule")]), "schedule/segment", &request, &request.body).await?)
+    }
+}
+
+#[async_trait(?Send)]
+pub trait DeleteScheduleSegment {
+    async fn delete_schedule_segment(&self, request: DeleteScheduleSegmentRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl DeleteScheduleSegment for ScheduleGroup {
+    async fn delete_schedule_segment(&self, request: DeleteScheduleSegmentRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::DELETE, Some(vec![String::from("channel:manage:schedule")]), "schedule/segment", &request).await?)
+    }
+}
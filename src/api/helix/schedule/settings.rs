@@ -0,0 +1,55 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::Timestamp;
+use super::ScheduleGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateChannelStreamScheduleRequest {
+    broadcaster_id: BroadcasterId,
+    is_vacation_enabled: Option<bool>,
+    vacation_start_time: Option<Timestamp>,
+    vacation_end_time: Option<Timestamp>,
+    timezone: Option<String>
+}
+
+impl UpdateChannelStreamScheduleRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    /// Starts a vacation between two RFC3339 timestamps, in the given IANA time zone.
+    pub fn with_vacation<S: Into<Timestamp>, T: Into<Timestamp>, U: Into<String>>(mut self, start_time: S, end_time: T, timezone: U) -> Self {
+        self.is_vacation_enabled = Some(true);
+        self.vacation_start_time = Some(start_time.into());
+        self.vacation_end_time = Some(end_time.into());
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    pub fn without_vacation(mut self) -> Self {
+        self.is_vacation_enabled = Some(false);
+        self.vacation_start_time = None;
+        self.vacation_end_time = None;
+        self.timezone = None;
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait UpdateChannelStreamSchedule {
+    async fn update_channel_stream_schedule(&self, request: UpdateChannelStreamScheduleRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl UpdateChannelStreamSchedule for ScheduleGroup {
+    async fn update_channel_stream_schedule(&self, request: UpdateChannelStreamScheduleRequest) -> Result<(), Box<dyn Error>> {
+        Ok(self.client.call_empty(ApiEndpoint::Helix, Method::PATCH, Some(vec![String::from("channel:manage:schedule")]), "schedule/settings", &request).await?)
+    }
+}
@@ -0,0 +1,63 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::result::Pagination;
+use crate::api::helix::id::{BroadcasterId, GameId};
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScheduleCategory {
+    pub id: GameId,
+    pub name: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScheduleSegment {
+    pub id: String,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub title: String,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub canceled_until: Option<Timestamp>,
+    pub category: Option<ScheduleCategory>,
+    pub is_recurring: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScheduleVacation {
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScheduleResponse {
+    /// `None` when the broadcaster has no scheduled segments.
+    pub segments: Option<Vec<ScheduleSegment>>,
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_name: String,
+    pub broadcaster_login: String,
+    pub vacation: Option<ScheduleVacation>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+/// Unlike most endpoints, the schedule endpoints return a single object as `data`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScheduleResult {
+    pub data: ScheduleResponse,
+    pub pagination: Option<Pagination>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
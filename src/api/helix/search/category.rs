@@ -1,10 +1,12 @@
 
-use std::error::Error;
+use std::{collections::HashMap, error::Error};
 use async_trait::async_trait;
 use serde::{Serialize, Deserialize};
+use serde_json::Value;
 use reqwest::Method;
 
 use crate::api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}};
+use crate::api::helix::id::GameId;
 use super::SearchGroup;
 
 #[derive(Debug, Default, Serialize)]
@@ -37,7 +39,10 @@ impl CategorySearchRequest {
 pub struct CategoryResponse {
     pub box_art_url: String,
     pub name: String,
-    pub id: String
+    pub id: GameId,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
 }
 
 #[async_trait(?Send)]
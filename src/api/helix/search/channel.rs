@@ -1,10 +1,13 @@
 
-use std::error::Error;
+use std::{collections::HashMap, error::Error};
 use async_trait::async_trait;
 use serde::{Serialize, Deserialize};
+use serde_json::Value;
 use reqwest::Method;
 
 use crate::api::{ApiEndpoint, helix::{result::ApiResult, pagination::ForwardPagination}};
+use crate::api::helix::id::{BroadcasterId, GameId, deserialize_optional_id};
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
 use super::SearchGroup;
 
 #[derive(Debug, Default, Serialize)]
@@ -35,15 +38,29 @@ impl ChannelSearchRequest {
 
 #[derive(Debug, Deserialize)]
 pub struct ChannelResponse {
-    pub game_id: String,
-    pub id: String,
+    /// `None` when the broadcaster has not set a category.
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
+    pub game_id: Option<GameId>,
+    #[serde(default)]
+    pub game_name: String,
+    pub id: BroadcasterId,
+    pub broadcaster_login: String,
     pub display_name: String,
     pub broadcaster_language: String,
     pub title: String,
     pub thumbnail_url: String,
     pub is_live: bool,
-    pub started_at: String,
-    pub tag_ids: Vec<String>
+    /// `None` while the channel is offline.
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub started_at: Option<Timestamp>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Deprecated by Twitch and always empty, use `tags`.
+    #[serde(default)]
+    pub tag_ids: Vec<String>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
 }
 
 #[async_trait(?Send)]
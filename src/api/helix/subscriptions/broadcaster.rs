@@ -0,0 +1,102 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::{api::{ApiEndpoint, helix::{result::ApiResult, pagination::*}}, util::extend_url};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::{SubscriptionsGroup, SubscriptionTier};
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetBroadcasterSubscriptionsRequest {
+    broadcaster_id: BroadcasterId,
+
+    #[serde(skip_serializing)]
+    user_ids: Option<Vec<UserId>>,
+
+    first: Option<u8>,
+
+    #[serde(flatten)]
+    pagination: Pagination
+}
+
+impl GetBroadcasterSubscriptionsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_user_ids<I: Into<UserId>>(mut self, user_ids: Vec<I>) -> Self {
+        self.user_ids = Some(user_ids.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    pub fn with_first(mut self, first: Option<u8>) -> Self {
+        self.first = first;
+        self
+    }
+
+    pub fn with_after<S: Into<String>>(mut self, after: Option<S>) -> Self {
+        self.pagination.forward = Some(ForwardPagination {
+            after: after.map(|x| x.into())
+        });
+        self.pagination.backward = None;
+        self
+    }
+
+    pub fn with_before<S: Into<String>>(mut self, before: Option<S>) -> Self {
+        self.pagination.forward = None;
+        self.pagination.backward = Some(BackwardPagination {
+            before: before.map(|x| x.into())
+        });
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BroadcasterSubscriptionResponse {
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_login: String,
+    pub broadcaster_name: String,
+    pub gifter_id: UserId,
+    pub gifter_login: String,
+    pub gifter_name: String,
+    pub is_gift: bool,
+    pub plan_name: String,
+    pub tier: SubscriptionTier,
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SubscriptionTotals {
+    /// Number of users subscribed to the broadcaster.
+    pub total: u64,
+    /// Subscriber points, tier 2 and 3 subscriptions are worth more than one point.
+    pub points: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetBroadcasterSubscriptions {
+    async fn get_broadcaster_subscriptions(&self, request: GetBroadcasterSubscriptionsRequest) -> Result<ApiResult<BroadcasterSubscriptionResponse, SubscriptionTotals>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetBroadcasterSubscriptions for SubscriptionsGroup {
+    async fn get_broadcaster_subscriptions(&self, request: GetBroadcasterSubscriptionsRequest) -> Result<ApiResult<BroadcasterSubscriptionResponse, SubscriptionTotals>, Box<dyn Error>> {
+        if request.user_ids.as_ref().map_or(0, |x| x.len()) > 100 {
+            return Err(String::from("Cannot request more than 100 users at once!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("channel:read:subscriptions")]), extend_url("subscriptions", "user_id", &request.user_ids)?, &request).await?)
+    }
+}
@@ -0,0 +1,36 @@
+
+use std::rc::Rc;
+use serde::{Serialize, Deserialize};
+use crate::TwitchClientInner;
+
+mod broadcaster;
+pub use broadcaster::*;
+
+mod user;
+pub use user::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum SubscriptionTier {
+    #[serde(rename = "1000")]
+    Tier1,
+    #[serde(rename = "2000")]
+    Tier2,
+    #[serde(rename = "3000")]
+    Tier3,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+pub struct SubscriptionsGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl SubscriptionsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
@@ -0,0 +1,63 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, ApiError, helix::result::ApiResult};
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::{SubscriptionsGroup, SubscriptionTier};
+
+#[derive(Debug, Default, Serialize)]
+pub struct CheckUserSubscriptionRequest {
+    broadcaster_id: BroadcasterId,
+    user_id: UserId
+}
+
+impl CheckUserSubscriptionRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+
+    pub fn with_user_id<I: Into<UserId>>(mut self, user_id: I) -> Self {
+        self.user_id = user_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserSubscriptionResponse {
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_login: String,
+    pub broadcaster_name: String,
+    pub is_gift: bool,
+    pub gifter_id: Option<UserId>,
+    pub gifter_login: Option<String>,
+    pub gifter_name: Option<String>,
+    pub tier: SubscriptionTier,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait CheckUserSubscription {
+    /// Returns an empty result when the user is not subscribed to the broadcaster.
+    async fn check_user_subscription(&self, request: CheckUserSubscriptionRequest) -> Result<ApiResult<UserSubscriptionResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl CheckUserSubscription for SubscriptionsGroup {
+    async fn check_user_subscription(&self, request: CheckUserSubscriptionRequest) -> Result<ApiResult<UserSubscriptionResponse>, Box<dyn Error>> {
+        match self.client.call(ApiEndpoint::Helix, Method::GET, Some(vec![String::from("user:read:subscriptions")]), "subscriptions/user", &request).await {
+            Err(error) if error.downcast_ref::<ApiError>().is_some_and(|x| x.status == 404) => Ok(ApiResult {
+                data: vec![],
+                pagination: None,
+                extra: Default::default()
+            }),
+            result => result
+        }
+    }
+}
@@ -0,0 +1,54 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::Timestamp;
+use super::TeamsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetChannelTeamsRequest {
+    broadcaster_id: BroadcasterId
+}
+
+impl GetChannelTeamsRequest {
+    pub fn with_broadcaster_id<I: Into<BroadcasterId>>(mut self, broadcaster_id: I) -> Self {
+        self.broadcaster_id = broadcaster_id.into();
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelTeamResponse {
+    pub broadcaster_id: BroadcasterId,
+    pub broadcaster_login: String,
+    pub broadcaster_name: String,
+    pub background_image_url: Option<String>,
+    pub banner: Option<String>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    pub info: String,
+    pub thumbnail_url: String,
+    pub team_name: String,
+    pub team_display_name: String,
+    pub id: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetChannelTeams {
+    async fn get_channel_teams(&self, request: GetChannelTeamsRequest) -> Result<ApiResult<ChannelTeamResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetChannelTeams for TeamsGroup {
+    async fn get_channel_teams(&self, request: GetChannelTeamsRequest) -> Result<ApiResult<ChannelTeamResponse>, Box<dyn Error>> {
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, "teams/channel", &request).await?)
+    }
+}
@@ -0,0 +1,74 @@
+
+use std::{collections::HashMap, error::Error};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use reqwest::Method;
+
+use crate::api::{ApiEndpoint, helix::result::ApiResult};
+use crate::api::helix::id::UserId;
+use crate::api::helix::time::Timestamp;
+use super::TeamsGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct GetTeamsRequest {
+    name: Option<String>,
+    id: Option<String>
+}
+
+impl GetTeamsRequest {
+    pub fn with_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self.id = None;
+        self
+    }
+
+    pub fn with_id<S: Into<String>>(mut self, id: S) -> Self {
+        self.name = None;
+        self.id = Some(id.into());
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamMember {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TeamResponse {
+    pub users: Vec<TeamMember>,
+    pub background_image_url: Option<String>,
+    pub banner: Option<String>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    pub info: String,
+    pub thumbnail_url: String,
+    pub team_name: String,
+    pub team_display_name: String,
+    pub id: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[async_trait(?Send)]
+pub trait GetTeams {
+    async fn get_teams(&self, request: GetTeamsRequest) -> Result<ApiResult<TeamResponse>, Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl GetTeams for TeamsGroup {
+    async fn get_teams(&self, request: GetTeamsRequest) -> Result<ApiResult<TeamResponse>, Box<dyn Error>> {
+        if request.name.is_none() && request.id.is_none() {
+            return Err(String::from("Either a team name or ID is required!").into());
+        }
+
+        Ok(self.client.call(ApiEndpoint::Helix, Method::GET, None, "teams", &request).await?)
+    }
+}
@@ -0,0 +1,21 @@
+
+use std::rc::Rc;
+use crate::TwitchClientInner;
+
+mod get;
+pub use get::*;
+
+mod channel;
+pub use channel::*;
+
+pub struct TeamsGroup {
+    client: Rc<TwitchClientInner>
+}
+
+impl TeamsGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client
+        }
+    }
+}
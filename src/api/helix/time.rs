@@ -0,0 +1,279 @@
+use std::{convert::TryFrom, error, fmt, str::FromStr, time::Duration};
+use serde::{Serialize, Deserialize, Deserializer, de::Error};
+
+/// An RFC3339 timestamp as sent by Twitch, see `to_datetime` with the `chrono` feature.
+///
+/// Only valid timestamps can be built, parse strings with `str::parse` or `Timestamp::new`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct Timestamp(String);
+
+/// Returned for a string that is not an RFC3339 timestamp.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTimestamp(pub String);
+
+impl fmt::Display for InvalidTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid RFC3339 timestamp {:?}!", self.0)
+    }
+}
+
+impl error::Error for InvalidTimestamp {}
+
+impl Timestamp {
+    pub fn new<S: Into<String>>(timestamp: S) -> Result<Self, InvalidTimestamp> {
+        let timestamp = timestamp.into();
+        match is_rfc3339(&timestamp) {
+            true => Ok(Self(timestamp)),
+            false => Err(InvalidTimestamp(timestamp))
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn to_datetime(&self) -> Result<chrono::DateTime<chrono::Utc>, chrono::ParseError> {
+        chrono::DateTime::parse_from_rfc3339(&self.0).map(|x| x.with_timezone(&chrono::Utc))
+    }
+}
+
+impl AsRef<str> for Timestamp {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = InvalidTimestamp;
+
+    fn from_str(timestamp: &str) -> Result<Self, Self::Err> {
+        Self::new(timestamp)
+    }
+}
+
+impl TryFrom<String> for Timestamp {
+    type Error = InvalidTimestamp;
+
+    fn try_from(timestamp: String) -> Result<Self, Self::Error> {
+        Self::new(timestamp)
+    }
+}
+
+impl TryFrom<&str> for Timestamp {
+    type Error = InvalidTimestamp;
+
+    fn try_from(timestamp: &str) -> Result<Self, Self::Error> {
+        Self::new(timestamp)
+    }
+}
+
+impl From<&Timestamp> for Timestamp {
+    fn from(timestamp: &Timestamp) -> Self {
+        timestamp.clone()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Timestamp where Tz::Offset: fmt::Display {
+    fn from(timestamp: chrono::DateTime<Tz>) -> Self {
+        Self(timestamp.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::new(String::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+/// Checks `YYYY-MM-DDTHH:MM:SS[.fraction](Z|+HH:MM|-HH:MM)`, including the length of the month.
+/// RFC3339 allows a space or lowercase letters in place of `T` and `Z`.
+fn is_rfc3339(timestamp: &str) -> bool {
+    fn number(bytes: &[u8], range: std::ops::RangeInclusive<u32>) -> Option<u32> {
+        if bytes.is_empty() || !bytes.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        let value = bytes.iter().fold(0, |x, y| x * 10 + u32::from(y - b'0'));
+        Some(value).filter(|x| range.contains(x))
+    }
+
+    let bytes = timestamp.as_bytes();
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || !matches!(bytes[10], b'T' | b't' | b' ') || bytes[13] != b':' || bytes[16] != b':' {
+        return false;
+    }
+
+    let date = (number(&bytes[0..4], 0..=9999), number(&bytes[5..7], 1..=12), number(&bytes[8..10], 1..=31));
+    let (year, month, day) = match date {
+        (Some(year), Some(month), Some(day)) => (year, month, day),
+        _ => return false
+    };
+
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31
+    };
+
+    // Leap seconds are allowed.
+    if day > days || number(&bytes[11..13], 0..=23).is_none() || number(&bytes[14..16], 0..=59).is_none() || number(&bytes[17..19], 0..=60).is_none() {
+        return false;
+    }
+
+    let mut offset = &bytes[19..];
+    if let Some(fraction) = offset.strip_prefix(b".") {
+        let digits = fraction.iter().take_while(|x| x.is_ascii_digit()).count();
+        if digits == 0 {
+            return false;
+        }
+        offset = &fraction[digits..];
+    }
+
+    match offset {
+        [b'Z'] | [b'z'] => true,
+        [b'+' | b'-', hours @ .., b':', _, _] if hours.len() == 2 => number(hours, 0..=23).is_some() && number(&offset[4..6], 0..=59).is_some(),
+        _ => false
+    }
+}
+
+/// Deserializes a timestamp that Twitch sends as an empty string or `null` when unset.
+pub fn deserialize_optional_timestamp<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Timestamp>, D::Error> {
+    match Option::<String>::deserialize(deserializer)? {
+        Some(timestamp) if !timestamp.is_empty() => Timestamp::new(timestamp).map(Some).map_err(D::Error::custom),
+        _ => Ok(None)
+    }
+}
+
+/// Parses a Twitch duration like `3h8m33s`, `8m33s` or `33s`.
+pub fn parse_duration(duration: &str) -> Option<Duration> {
+    if duration.is_empty() {
+        return None;
+    }
+
+    let mut seconds = 0u64;
+    let mut value: Option<u64> = None;
+    let mut last_unit = u64::MAX;
+
+    for c in duration.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            value = Some(value.unwrap_or(0).checked_mul(10)?.checked_add(u64::from(digit))?);
+            continue;
+        }
+
+        let unit = match c {
+            'd' => 86400,
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None
+        };
+
+        // Units must be present at most once and in descending order.
+        if unit >= last_unit {
+            return None;
+        }
+        last_unit = unit;
+        seconds = seconds.checked_add(value.take()?.checked_mul(unit)?)?;
+    }
+
+    match value {
+        Some(_) => None,
+        None => Some(Duration::from_secs(seconds))
+    }
+}
+
+/// Deserializes a Twitch duration string, see `parse_duration`.
+pub fn deserialize_duration<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    let duration = String::deserialize(deserializer)?;
+    parse_duration(&duration).ok_or_else(|| D::Error::custom(format!("invalid duration {:?}", duration)))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("3h8m33s"), Some(Duration::from_secs(3 * 3600 + 8 * 60 + 33)));
+        assert_eq!(parse_duration("8m33s"), Some(Duration::from_secs(513)));
+        assert_eq!(parse_duration("33s"), Some(Duration::from_secs(33)));
+        assert_eq!(parse_duration("1d0s"), Some(Duration::from_secs(86400)));
+        assert_eq!(parse_duration("0s"), Some(Duration::from_secs(0)));
+    }
+
+    #[test]
+    fn invalid_durations() {
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("33s8m"), None);
+        assert_eq!(parse_duration("1h1h"), None);
+        assert_eq!(parse_duration("3h8m33"), None);
+        assert_eq!(parse_duration("33"), None);
+        assert_eq!(parse_duration("h"), None);
+        assert_eq!(parse_duration("3x"), None);
+        assert_eq!(parse_duration("18446744073709551616s"), None);
+        assert_eq!(parse_duration("18446744073709551615h"), None);
+        assert_eq!(parse_duration("18446744073709551615h1s"), None);
+    }
+
+    #[test]
+    fn deserialize() {
+        #[derive(Deserialize)]
+        struct Data {
+            #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+            at: Option<Timestamp>,
+            #[serde(deserialize_with = "deserialize_duration")]
+            duration: Duration
+        }
+
+        let data: Data = serde_json::from_value(json!({ "at": "2021-03-10T15:04:21Z", "duration": "1m5s" })).unwrap();
+        assert_eq!(data.at.as_ref().map(Timestamp::as_str), Some("2021-03-10T15:04:21Z"));
+        assert_eq!(data.duration, Duration::from_secs(65));
+
+        let data: Data = serde_json::from_value(json!({ "at": "", "duration": "0s" })).unwrap();
+        assert_eq!(data.at, None);
+        let data: Data = serde_json::from_value(json!({ "at": null, "duration": "0s" })).unwrap();
+        assert_eq!(data.at, None);
+
+        assert!(serde_json::from_value::<Data>(json!({ "duration": "5" })).is_err());
+        assert!(serde_json::from_value::<Data>(json!({ "at": "yesterday", "duration": "0s" })).is_err());
+        assert!(serde_json::from_value::<Timestamp>(json!("2021-03-10")).is_err());
+    }
+
+    #[test]
+    fn timestamps() {
+        for timestamp in ["2021-03-10T15:04:21Z", "2021-03-10T15:04:21.634234626Z", "2021-03-10T16:04:21.5+01:00", "2021-03-10t15:04:21z", "2020-02-29T23:59:60-12:00"] {
+            assert_eq!(timestamp.parse::<Timestamp>().map(Timestamp::into_string), Ok(String::from(timestamp)));
+        }
+
+        for timestamp in ["", "yesterday", "2021-03-10", "2021-03-10T15:04:21", "2021-03-10T15:04:21.Z", "2021-02-29T15:04:21Z", "2021-13-10T15:04:21Z", "2021-03-10T24:04:21Z", "2021-03-10T15:04:21+1:00", "2021-03-10T15:04:21+01:60", "+021-03-10T15:04:21Z"] {
+            assert_eq!(Timestamp::try_from(timestamp), Err(InvalidTimestamp(String::from(timestamp))));
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn datetime() {
+        use chrono::{TimeZone, Utc};
+
+        let timestamp = Timestamp::new("2021-03-10T16:04:21.5+01:00").unwrap();
+        assert_eq!(timestamp.to_datetime().unwrap(), Utc.timestamp_opt(1615388661, 500_000_000).unwrap());
+
+        let timestamp = Timestamp::from(Utc.timestamp_opt(1615388661, 0).unwrap());
+        assert_eq!(timestamp.as_str(), "2021-03-10T15:04:21Z");
+    }
+}
@@ -0,0 +1,24 @@
+
+use std::{rc::Rc, time::Duration};
+use crate::{TwitchClientInner, util::RateLimiter};
+
+mod send;
+pub use send::*;
+
+pub struct WhispersGroup {
+    client: Rc<TwitchClientInner>,
+    limiter: RateLimiter
+}
+
+impl WhispersGroup {
+    pub(crate) fn new(client: Rc<TwitchClientInner>) -> Self {
+        Self {
+            client,
+            // Whispers are limited separately from the rest of the API, to 3 per second and 100 per minute.
+            limiter: RateLimiter::new(vec![
+                (3, Duration::from_secs(1)),
+                (100, Duration::from_secs(60))
+            ])
+        }
+    }
+}
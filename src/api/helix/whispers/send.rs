@@ -0,0 +1,65 @@
+
+use std::error::Error;
+use async_trait::async_trait;
+use serde::Serialize;
+use reqwest::Method;
+
+use crate::api::ApiEndpoint;
+use crate::api::helix::id::UserId;
+use super::WhispersGroup;
+
+#[derive(Debug, Default, Serialize)]
+pub struct SendWhisperBody {
+    message: String
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct SendWhisperRequest {
+    from_user_id: UserId,
+    to_user_id: UserId,
+
+    #[serde(skip_serializing)]
+    body: SendWhisperBody
+}
+
+impl SendWhisperRequest {
+    /// Must be the user the access token belongs to, and have a verified phone number.
+    pub fn with_from_user_id<I: Into<UserId>>(mut self, from_user_id: I) -> Self {
+        self.from_user_id = from_user_id.into();
+        self
+    }
+
+    pub fn with_to_user_id<I: Into<UserId>>(mut self, to_user_id: I) -> Self {
+        self.to_user_id = to_user_id.into();
+        self
+    }
+
+    /// Up to 500 characters for a first whisper, 10000 when the recipient has whispered the sender before.
+    pub fn with_message<S: Into<String>>(mut self, message: S) -> Self {
+        self.body.message = message.into();
+        self
+    }
+}
+
+#[async_trait(?Send)]
+pub trait SendWhisper {
+    /// Waits for the whisper rate limits before sending, Twitch additionally limits whispers to 40 unique recipients per day.
+    async fn send_whisper(&self, request: SendWhisperRequest) -> Result<(), Box<dyn Error>>;
+}
+
+#[async_trait(?Send)]
+impl SendWhisper for WhispersGroup {
+    async fn send_whisper(&self, request: SendWhisperRequest) -> Result<(), Box<dyn Error>> {
+        if request.body.message.is_empty() || request.body.message.chars().count() > 10_000 {
+            return Err(String::from("Whisper must be between 1 and 10000 characters!").into());
+        }
+
+        if request.from_user_id == request.to_user_id {
+            return Err(String::from("Users cannot whisper themselves!").into());
+        }
+
+        self.limiter.acquire().await;
+
+        Ok(self.client.call_empty_with_body(ApiEndpoint::Helix, Method::POST, Some(vec![String::from("user:manage:whispers")]), "whispers", &request, &request.body).await?)
+    }
+}
@@ -1,12 +1,14 @@
 
-use std::error::Error;
+use std::{error::Error, fmt};
 use reqwest::Url;
+use serde::{Serialize, Deserialize};
 
 #[cfg(feature = "helix")]
 pub mod helix;
 
 pub(crate) enum ApiEndpoint {
     #[cfg(feature = "helix")]
+    #[allow(dead_code)]
     Helix,
     Auth,
     #[allow(dead_code)]
@@ -24,3 +26,22 @@ impl ApiEndpoint {
         Ok(endpoint.join(url)?)
     }
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiError {
+    pub error: String,
+    pub status: u16,
+    pub message: String,
+
+    /// Unix timestamp of when the rate limit bucket refills, set on `429 Too Many Requests`.
+    #[serde(skip)]
+    pub ratelimit_reset: Option<u64>
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.status, self.error, self.message)
+    }
+}
+
+impl Error for ApiError {}
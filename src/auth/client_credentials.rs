@@ -29,8 +29,8 @@ impl ClientCredentialsAuthProvider {
             client: BasicClient::new(
                 ClientId::new(client_id),
                 Some(ClientSecret::new(client_secret)),
-                AuthUrl::new(ApiEndpoint::Auth.get_endpoint("authorize")?.into_string())?,
-                Some(TokenUrl::new(ApiEndpoint::Auth.get_endpoint("token")?.into_string())?)
+                AuthUrl::new(ApiEndpoint::Auth.get_endpoint("authorize")?.to_string())?,
+                Some(TokenUrl::new(ApiEndpoint::Auth.get_endpoint("token")?.to_string())?)
             ).set_auth_type(AuthType::RequestBody)
         })
     }
@@ -65,8 +65,8 @@ impl AuthProvider for ClientCredentialsAuthProvider {
             access_token: result.access_token().secret().to_owned(),
             refresh_token: result.refresh_token().to_owned().map(|x| x.secret().to_owned()),
             expires_in: result.expires_in().to_owned(),
-            scopes: result.scopes().to_owned()
-                .map(|x| x.to_owned().into_iter().map(|x| x.into()).collect()),
+            scopes: result.scopes()
+                .map(|x| x.iter().map(|x| x.to_string()).collect()),
             time: Some(Instant::now())
         })
     }
@@ -90,8 +90,8 @@ impl AuthProvider for ClientCredentialsAuthProvider {
         access_token.access_token = result.access_token().secret().to_owned();
         access_token.refresh_token = result.refresh_token().to_owned().map(|x| x.secret().to_owned());
         access_token.expires_in = result.expires_in().to_owned();
-        access_token.scopes = result.scopes().to_owned()
-            .map(|x| x.to_owned().into_iter().map(|x| x.into()).collect());
+        access_token.scopes = result.scopes()
+            .map(|x| x.iter().map(|x| x.to_string()).collect());
         access_token.time = Some(Instant::now());
         Ok(())
     }
@@ -0,0 +1,380 @@
+
+use std::{error::Error, time::{Duration, SystemTime, UNIX_EPOCH}};
+use futures::Stream;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use crate::{TwitchClient, util::RateLimiter};
+use super::{ChatLine, connection::{ChatTransport, Connection, ConnectionError}};
+
+/// How long to wait for the server to accept the login.
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone)]
+pub enum ChatEvent {
+    /// Use `ChatLine::event` for the typed event.
+    Message(ChatLine),
+    /// The connection ended, with the error that ended it if there was one.
+    Disconnected(Option<String>)
+}
+
+#[derive(Clone)]
+pub struct ChatConfig {
+    transport: ChatTransport,
+    pub(super) login: Option<String>,
+    pub(super) message_limit: (usize, Duration),
+    pub(super) join_limit: (usize, Duration)
+}
+
+impl Default for ChatConfig {
+    fn default() -> Self {
+        Self {
+            transport: ChatTransport::default(),
+            login: None,
+            message_limit: (20, Duration::from_secs(30)),
+            join_limit: (20, Duration::from_secs(10))
+        }
+    }
+}
+
+impl ChatConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_transport(mut self, transport: ChatTransport) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Login of the user the access token belongs to.
+    pub fn with_login<S: Into<String>>(mut self, login: S) -> Self {
+        self.login = Some(login.into().to_lowercase());
+        self
+    }
+
+    /// Defaults to 20 messages per 30 seconds, moderators and broadcasters may send 100. A count of 0 is raised to 1.
+    pub fn with_message_limit(mut self, count: usize, window: Duration) -> Self {
+        self.message_limit = (count.max(1), window);
+        self
+    }
+
+    /// Defaults to 20 joins per 10 seconds. A count of 0 is raised to 1.
+    pub fn with_join_limit(mut self, count: usize, window: Duration) -> Self {
+        self.join_limit = (count.max(1), window);
+        self
+    }
+}
+
+/// A TMI connection running on its own task, incoming lines are read from `events`.
+pub struct ChatClient {
+    login: String,
+    outgoing: UnboundedSender<String>,
+    events: UnboundedReceiver<ChatEvent>,
+    message_limiter: RateLimiter,
+    join_limiter: RateLimiter
+}
+
+impl ChatClient {
+    /// Logs in with the access token of the client's auth provider, which must be a user token with the chat scopes.
+    pub async fn connect(client: &TwitchClient, config: ChatConfig) -> Result<Self, Box<dyn Error>> {
+        let login = config.login.clone()
+            .ok_or_else(|| String::from("Chat login is required!"))?;
+        let token = client.access_token().await?;
+
+        Self::open(config, login, Some(token)).await
+    }
+
+    pub(super) fn anonymous_login() -> String {
+        let suffix = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |x| x.subsec_nanos()) % 100_000;
+        format!("justinfan{}", suffix)
+    }
+
+    /// Logs in anonymously, which can read chat but not send to it.
+    pub async fn connect_anonymous(config: ChatConfig) -> Result<Self, Box<dyn Error>> {
+        Self::open(config, Self::anonymous_login(), None).await
+    }
+
+    pub(super) async fn open(config: ChatConfig, login: String, token: Option<String>) -> Result<Self, Box<dyn Error>> {
+        let mut connection = Connection::open(&config.transport).await?;
+        let (events_sender, events) = unbounded_channel();
+
+        connection.write_line("CAP REQ :twitch.tv/tags twitch.tv/commands twitch.tv/membership").await?;
+        if let Some(token) = token {
+            connection.write_line(&format!("PASS oauth:{}", token.trim_start_matches("oauth:"))).await?;
+        }
+        connection.write_line(&format!("NICK {}", login)).await?;
+
+        tokio::time::timeout(LOGIN_TIMEOUT, login_handshake(&mut connection, &events_sender)).await
+            .map_err(|_| String::from("Timed out waiting for chat login!"))??;
+
+        let (outgoing, outgoing_receiver) = unbounded_channel();
+        tokio::spawn(run(connection, outgoing_receiver, events_sender));
+
+        Ok(Self {
+            login,
+            outgoing,
+            events,
+            message_limiter: RateLimiter::new(vec![config.message_limit]),
+            join_limiter: RateLimiter::new(vec![config.join_limit])
+        })
+    }
+
+    pub fn login(&self) -> &str {
+        &self.login
+    }
+
+    /// Incoming messages, ending after `ChatEvent::Disconnected`.
+    pub fn events(&mut self) -> impl Stream<Item = ChatEvent> + '_ {
+        &mut self.events
+    }
+
+    pub async fn next_event(&mut self) -> Option<ChatEvent> {
+        self.events.recv().await
+    }
+
+    pub async fn join<S: AsRef<str>>(&self, channel: S) -> Result<(), Box<dyn Error>> {
+        let channel = normalize_channel(channel.as_ref())?;
+        self.join_limiter.acquire().await;
+        self.send_raw(format!("JOIN #{}", channel))
+    }
+
+    pub async fn part<S: AsRef<str>>(&self, channel: S) -> Result<(), Box<dyn Error>> {
+        let channel = normalize_channel(channel.as_ref())?;
+        self.send_raw(format!("PART #{}", channel))
+    }
+
+    pub async fn send_message<S: AsRef<str>, T: AsRef<str>>(&self, channel: S, text: T) -> Result<(), Box<dyn Error>> {
+        let channel = normalize_channel(channel.as_ref())?;
+        let text = validate_text(text.as_ref())?;
+        self.message_limiter.acquire().await;
+        self.send_raw(format!("PRIVMSG #{} :{}", channel, text))
+    }
+
+    /// Replies to the message with the given `id` tag.
+    pub async fn reply<S: AsRef<str>, T: AsRef<str>, U: AsRef<str>>(&self, channel: S, parent_id: T, text: U) -> Result<(), Box<dyn Error>> {
+        let channel = normalize_channel(channel.as_ref())?;
+        let parent_id = validate_parent_id(parent_id.as_ref())?;
+        let text = validate_text(text.as_ref())?;
+        self.message_limiter.acquire().await;
+        self.send_raw(format!("@reply-parent-msg-id={} PRIVMSG #{} :{}", parent_id, channel, text))
+    }
+
+    /// Sends a line as is, without rate limiting.
+    pub fn send_raw<S: Into<String>>(&self, line: S) -> Result<(), Box<dyn Error>> {
+        self.outgoing.send(line.into())
+            .map_err(|_| String::from("Chat connection is closed!").into())
+    }
+}
+
+pub(super) fn normalize_channel(channel: &str) -> Result<String, Box<dyn Error>> {
+    let channel = channel.trim_start_matches('#').to_lowercase();
+    if channel.is_empty() || channel.contains(|x: char| x.is_whitespace() || x == ',') {
+        return Err(String::from("Invalid channel name!").into());
+    }
+
+    Ok(channel)
+}
+
+pub(super) fn validate_text(text: &str) -> Result<&str, Box<dyn Error>> {
+    if text.is_empty() || text.chars().count() > 500 {
+        return Err(String::from("Chat message must be between 1 and 500 characters!").into());
+    }
+
+    if text.contains(['\r', '\n']) {
+        return Err(String::from("Chat message cannot contain line breaks!").into());
+    }
+
+    Ok(text)
+}
+
+/// Ends up in a tag, so anything that would end the tag or the tag list is rejected.
+pub(super) fn validate_parent_id(parent_id: &str) -> Result<&str, Box<dyn Error>> {
+    if parent_id.is_empty() || parent_id.contains(|x: char| x.is_whitespace() || x == ';') {
+        return Err(String::from("Invalid reply parent id!").into());
+    }
+
+    Ok(parent_id)
+}
+
+async fn login_handshake(connection: &mut Connection, events: &UnboundedSender<ChatEvent>) -> Result<(), Box<dyn Error>> {
+    while let Some(line) = connection.read_line().await {
+        let line = match ChatLine::new(line?) {
+            Ok(line) => line,
+            Err(_) => continue
+        };
+
+        let message = line.message();
+        match message.command() {
+            "001" => {
+                let _ = events.send(ChatEvent::Message(line));
+                return Ok(());
+            },
+            "NOTICE" if message.channel().is_none() => {
+                return Err(format!("Chat login failed: {}", message.text().unwrap_or_default()).into());
+            },
+            "PING" => connection.write_line(&format!("PONG :{}", message.text().unwrap_or("tmi.twitch.tv"))).await?,
+            _ => {}
+        }
+
+        let _ = events.send(ChatEvent::Message(line));
+    }
+
+    Err(String::from("Chat connection closed during login!").into())
+}
+
+enum Step {
+    Incoming(Option<Result<String, ConnectionError>>),
+    Outgoing(Option<String>)
+}
+
+async fn run(mut connection: Connection, mut outgoing: UnboundedReceiver<String>, events: UnboundedSender<ChatEvent>) {
+    let reason = loop {
+        let step = tokio::select! {
+            line = connection.read_line() => Step::Incoming(line),
+            line = outgoing.recv() => Step::Outgoing(line)
+        };
+
+        match step {
+            Step::Incoming(Some(Ok(line))) => {
+                let line = match ChatLine::new(line) {
+                    Ok(line) => line,
+                    Err(_) => continue
+                };
+
+                let message = line.message();
+                if message.command() == "PING" {
+                    if let Err(error) = connection.write_line(&format!("PONG :{}", message.text().unwrap_or("tmi.twitch.tv"))).await {
+                        break Some(error);
+                    }
+                }
+
+                if events.send(ChatEvent::Message(line)).is_err() {
+                    break None;
+                }
+            },
+            Step::Incoming(Some(Err(error))) => break Some(error),
+            Step::Outgoing(Some(line)) => {
+                if let Err(error) = connection.write_line(&line).await {
+                    break Some(error);
+                }
+            },
+            Step::Incoming(None) | Step::Outgoing(None) => break None
+        }
+    };
+
+    let _ = events.send(ChatEvent::Disconnected(reason));
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{io::{split, AsyncBufReadExt, AsyncWriteExt, BufReader, Lines, ReadHalf, WriteHalf}, net::{TcpListener, TcpStream}};
+    use super::*;
+
+    type ServerLines = Lines<BufReader<ReadHalf<TcpStream>>>;
+
+    /// A fake chat server on a local port, with the config to reach it.
+    async fn server() -> (TcpListener, ChatConfig) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let transport = ChatTransport::Tcp {
+            host: String::from("127.0.0.1"),
+            port: listener.local_addr().unwrap().port()
+        };
+
+        (listener, ChatConfig::new().with_transport(transport))
+    }
+
+    async fn accept(listener: &mut TcpListener) -> (ServerLines, WriteHalf<TcpStream>) {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (reader, writer) = split(stream);
+        (BufReader::new(reader).lines(), writer)
+    }
+
+    async fn next_line(lines: &mut ServerLines) -> String {
+        lines.next_line().await.unwrap().unwrap()
+    }
+
+    #[tokio::test]
+    async fn login_and_run() {
+        let (mut listener, config) = server().await;
+
+        let server = tokio::spawn(async move {
+            let (mut lines, mut writer) = accept(&mut listener).await;
+            assert_eq!(next_line(&mut lines).await, "CAP REQ :twitch.tv/tags twitch.tv/commands twitch.tv/membership");
+            assert_eq!(next_line(&mut lines).await, "PASS oauth:s3cr3t");
+            assert_eq!(next_line(&mut lines).await, "NICK cool_user");
+
+            writer.write_all(b"PING :tmi.twitch.tv\r\n").await.unwrap();
+            assert_eq!(next_line(&mut lines).await, "PONG :tmi.twitch.tv");
+            writer.write_all(b":tmi.twitch.tv 001 cool_user :Welcome, GLHF!\r\n").await.unwrap();
+
+            writer.write_all(b"PING :tmi.twitch.tv\r\n").await.unwrap();
+            assert_eq!(next_line(&mut lines).await, "PONG :tmi.twitch.tv");
+            assert_eq!(next_line(&mut lines).await, "JOIN #foo");
+        });
+
+        let mut client = ChatClient::open(config, String::from("cool_user"), Some(String::from("oauth:s3cr3t"))).await.unwrap();
+        let commands = |event: Option<ChatEvent>| match event {
+            Some(ChatEvent::Message(line)) => line.message().command().to_owned(),
+            event => panic!("unexpected event {:?}", event)
+        };
+
+        assert_eq!(commands(client.next_event().await), "PING");
+        assert_eq!(commands(client.next_event().await), "001");
+        assert_eq!(commands(client.next_event().await), "PING");
+
+        client.join("#Foo").await.unwrap();
+        server.await.unwrap();
+
+        match client.next_event().await {
+            Some(ChatEvent::Disconnected(None)) => {},
+            event => panic!("unexpected event {:?}", event)
+        }
+    }
+
+    #[tokio::test]
+    async fn login_failure() {
+        let (mut listener, config) = server().await;
+
+        tokio::spawn(async move {
+            let (mut lines, mut writer) = accept(&mut listener).await;
+            while !next_line(&mut lines).await.starts_with("NICK") {}
+            writer.write_all(b":tmi.twitch.tv NOTICE * :Login authentication failed\r\n").await.unwrap();
+        });
+
+        let error = ChatClient::open(config, String::from("cool_user"), Some(String::from("bad"))).await.err().unwrap();
+        assert_eq!(error.to_string(), "Chat login failed: Login authentication failed");
+    }
+
+    #[test]
+    fn limits_are_at_least_one() {
+        let config = ChatConfig::new().with_message_limit(0, Duration::from_secs(30)).with_join_limit(0, Duration::from_secs(10));
+        assert_eq!(config.message_limit.0, 1);
+        assert_eq!(config.join_limit.0, 1);
+    }
+
+    #[test]
+    fn channels() {
+        assert_eq!(normalize_channel("#Foo").unwrap(), "foo");
+        assert_eq!(normalize_channel("bar").unwrap(), "bar");
+        assert!(normalize_channel("#").is_err());
+        assert!(normalize_channel("foo bar").is_err());
+        assert!(normalize_channel("foo,bar").is_err());
+    }
+
+    #[test]
+    fn texts() {
+        assert!(validate_text("hello").is_ok());
+        assert!(validate_text("").is_err());
+        assert!(validate_text(&"a".repeat(501)).is_err());
+        assert!(validate_text("hello\r\nPART #foo").is_err());
+    }
+
+    #[test]
+    fn parent_ids() {
+        assert_eq!(validate_parent_id("b34ccfc7-4977-403a-8a94-33c6bac34fb8").unwrap(), "b34ccfc7-4977-403a-8a94-33c6bac34fb8");
+        assert!(validate_parent_id("").is_err());
+        assert!(validate_parent_id("abc;mod=1").is_err());
+        assert!(validate_parent_id("abc PRIVMSG").is_err());
+        assert!(validate_parent_id("abc\r\nPART #foo").is_err());
+    }
+}
@@ -0,0 +1,109 @@
+
+use std::{collections::VecDeque, sync::Arc};
+use async_tungstenite::{tokio::{connect_async, ConnectStream}, tungstenite::Message, WebSocketStream};
+use futures::{SinkExt, StreamExt};
+use tokio::{io::{split, AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader, Lines, ReadHalf, WriteHalf}, net::TcpStream};
+use tokio_rustls::{rustls::ClientConfig, webpki::DNSNameRef, TlsConnector};
+
+/// Where to reach chat, defaults to TLS on `irc.chat.twitch.tv:6697`.
+#[derive(Debug, Clone)]
+pub enum ChatTransport {
+    Tcp {
+        host: String,
+        port: u16
+    },
+    Tls {
+        host: String,
+        port: u16
+    },
+    WebSocket {
+        url: String
+    }
+}
+
+impl Default for ChatTransport {
+    fn default() -> Self {
+        ChatTransport::Tls {
+            host: String::from("irc.chat.twitch.tv"),
+            port: 6697
+        }
+    }
+}
+
+pub(crate) trait Io: AsyncRead + AsyncWrite + Unpin + Send {}
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Io for T {}
+
+/// Errors are strings so the connection can live on its own task.
+pub(crate) type ConnectionError = String;
+
+/// A line based connection to chat, independent of the transport.
+pub(crate) enum Connection {
+    Stream {
+        lines: Lines<BufReader<ReadHalf<Box<dyn Io>>>>,
+        writer: WriteHalf<Box<dyn Io>>
+    },
+    WebSocket {
+        socket: Box<WebSocketStream<ConnectStream>>,
+        buffer: VecDeque<String>
+    }
+}
+
+impl Connection {
+    pub(crate) async fn open(transport: &ChatTransport) -> Result<Self, ConnectionError> {
+        let stream: Box<dyn Io> = match transport {
+            ChatTransport::Tcp { host, port } => Box::new(TcpStream::connect((host.as_str(), *port)).await.map_err(|x| x.to_string())?),
+            ChatTransport::Tls { host, port } => {
+                let stream = TcpStream::connect((host.as_str(), *port)).await.map_err(|x| x.to_string())?;
+
+                let mut config = ClientConfig::new();
+                config.root_store.add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS);
+                let domain = DNSNameRef::try_from_ascii_str(host).map_err(|x| x.to_string())?;
+
+                Box::new(TlsConnector::from(Arc::new(config)).connect(domain, stream).await.map_err(|x| x.to_string())?)
+            },
+            ChatTransport::WebSocket { url } => {
+                let (socket, _) = connect_async(url.as_str()).await.map_err(|x| x.to_string())?;
+
+                return Ok(Connection::WebSocket {
+                    socket: Box::new(socket),
+                    buffer: VecDeque::new()
+                });
+            }
+        };
+
+        let (reader, writer) = split(stream);
+        Ok(Connection::Stream {
+            lines: BufReader::new(reader).lines(),
+            writer
+        })
+    }
+
+    /// Returns `None` once the connection is closed, safe to cancel.
+    pub(crate) async fn read_line(&mut self) -> Option<Result<String, ConnectionError>> {
+        match self {
+            Connection::Stream { lines, .. } => lines.next().await.map(|x| x.map_err(|x| x.to_string())),
+            Connection::WebSocket { socket, buffer } => loop {
+                if let Some(line) = buffer.pop_front() {
+                    return Some(Ok(line));
+                }
+
+                match socket.next().await? {
+                    Ok(Message::Text(text)) => buffer.extend(text.split("\r\n").filter(|x| !x.is_empty()).map(String::from)),
+                    Ok(Message::Close(_)) => return None,
+                    Ok(_) => {},
+                    Err(error) => return Some(Err(error.to_string()))
+                }
+            }
+        }
+    }
+
+    pub(crate) async fn write_line(&mut self, line: &str) -> Result<(), ConnectionError> {
+        match self {
+            Connection::Stream { writer, .. } => {
+                writer.write_all(format!("{}\r\n", line).as_bytes()).await.map_err(|x| x.to_string())?;
+                writer.flush().await.map_err(|x| x.to_string())
+            },
+            Connection::WebSocket { socket, .. } => socket.send(Message::Text(line.to_owned())).await.map_err(|x| x.to_string())
+        }
+    }
+}
@@ -0,0 +1,645 @@
+
+use std::{borrow::Cow, str::FromStr};
+use super::{IrcMessage, IrcTags, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Badge<'a> {
+    pub name: &'a str,
+    pub version: &'a str
+}
+
+/// Where an emote appears in the message text, as inclusive character (not byte) offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmoteRange<'a> {
+    pub id: &'a str,
+    pub start: usize,
+    pub end: usize
+}
+
+/// Parses a `badges` or `badge-info` tag, such as `moderator/1,subscriber/12`.
+pub fn parse_badges(value: &str) -> Vec<Badge<'_>> {
+    value.split(',')
+        .filter_map(|x| x.split_once('/'))
+        .map(|(name, version)| Badge {
+            name,
+            version
+        })
+        .collect()
+}
+
+/// Parses an `emotes` tag, such as `25:0-4,12-16/1902:6-10`. Malformed ranges are skipped.
+pub fn parse_emotes(value: &str) -> Vec<EmoteRange<'_>> {
+    value.split('/')
+        .filter_map(|x| x.split_once(':'))
+        .flat_map(|(id, ranges)| ranges.split(',').filter_map(move |range| {
+            let (start, end) = range.split_once('-')?;
+            Some(EmoteRange {
+                id,
+                start: start.parse().ok()?,
+                end: end.parse().ok()?
+            })
+        }))
+        .collect()
+}
+
+fn required<'a>(value: Option<&'a str>, field: &'static str) -> Result<&'a str, ParseError> {
+    value.ok_or(ParseError::MissingField(field))
+}
+
+fn number<T: FromStr>(tags: &IrcTags<'_>, key: &'static str) -> Result<Option<T>, ParseError> {
+    tags.get_nonempty(key)
+        .map(|x| x.parse().map_err(|_| ParseError::InvalidField(key)))
+        .transpose()
+}
+
+fn flag(tags: &IrcTags<'_>, key: &'static str) -> Option<bool> {
+    tags.get_nonempty(key).map(|x| x != "0")
+}
+
+fn list<'a>(tags: &IrcTags<'a>, key: &'static str) -> Vec<&'a str> {
+    tags.get_nonempty(key).map_or_else(Vec::new, |x| x.split(',').collect())
+}
+
+#[derive(Debug, Clone)]
+pub struct PrivMsg<'a> {
+    pub channel: &'a str,
+    pub id: Option<&'a str>,
+    pub sender_id: Option<&'a str>,
+    pub sender_login: &'a str,
+    pub display_name: Option<Cow<'a, str>>,
+    pub text: &'a str,
+    /// Sent with `/me`, the `ACTION` wrapper is removed from `text`.
+    pub is_action: bool,
+    pub badges: Vec<Badge<'a>>,
+    pub badge_info: Vec<Badge<'a>>,
+    pub emotes: Vec<EmoteRange<'a>>,
+    pub color: Option<&'a str>,
+    pub bits: Option<u64>,
+    pub reply_parent_msg_id: Option<&'a str>,
+    pub is_first_msg: bool,
+    pub tmi_sent_ts: Option<u64>,
+    pub tags: IrcTags<'a>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubPlan<'a> {
+    Prime,
+    Tier1,
+    Tier2,
+    Tier3,
+    Other(&'a str)
+}
+
+impl<'a> From<&'a str> for SubPlan<'a> {
+    fn from(value: &'a str) -> Self {
+        match value {
+            "Prime" => SubPlan::Prime,
+            "1000" => SubPlan::Tier1,
+            "2000" => SubPlan::Tier2,
+            "3000" => SubPlan::Tier3,
+            other => SubPlan::Other(other)
+        }
+    }
+}
+
+/// The `msg-id` specific part of a `USERNOTICE`, from its `msg-param-*` tags.
+#[derive(Debug, Clone)]
+pub enum UserNoticeKind<'a> {
+    Sub {
+        cumulative_months: u32,
+        streak_months: Option<u32>,
+        plan: SubPlan<'a>,
+        plan_name: Option<Cow<'a, str>>
+    },
+    Resub {
+        cumulative_months: u32,
+        streak_months: Option<u32>,
+        plan: SubPlan<'a>,
+        plan_name: Option<Cow<'a, str>>
+    },
+    SubGift {
+        recipient_id: &'a str,
+        recipient_login: &'a str,
+        recipient_display_name: Option<Cow<'a, str>>,
+        months: Option<u32>,
+        gift_months: Option<u32>,
+        plan: SubPlan<'a>,
+        plan_name: Option<Cow<'a, str>>
+    },
+    SubMysteryGift {
+        count: u32,
+        plan: SubPlan<'a>
+    },
+    Raid {
+        source_login: &'a str,
+        source_display_name: Option<Cow<'a, str>>,
+        viewer_count: u64
+    },
+    Ritual {
+        name: &'a str
+    },
+    Announcement {
+        color: Option<&'a str>
+    },
+    Other(&'a str)
+}
+
+#[derive(Debug, Clone)]
+pub struct UserNotice<'a> {
+    pub channel: &'a str,
+    pub id: Option<&'a str>,
+    pub sender_id: Option<&'a str>,
+    pub sender_login: Option<&'a str>,
+    pub display_name: Option<Cow<'a, str>>,
+    pub system_msg: Option<Cow<'a, str>>,
+    /// The message the user attached, if any.
+    pub text: Option<&'a str>,
+    pub badges: Vec<Badge<'a>>,
+    pub emotes: Vec<EmoteRange<'a>>,
+    pub kind: UserNoticeKind<'a>,
+    pub tags: IrcTags<'a>
+}
+
+/// A ban, timeout or, without a target, the whole chat being cleared.
+#[derive(Debug, Clone)]
+pub struct ClearChat<'a> {
+    pub channel: &'a str,
+    pub room_id: Option<&'a str>,
+    pub target_login: Option<&'a str>,
+    pub target_user_id: Option<&'a str>,
+    /// Seconds for a timeout, `None` for a permanent ban.
+    pub ban_duration: Option<u64>,
+    pub tags: IrcTags<'a>
+}
+
+#[derive(Debug, Clone)]
+pub struct ClearMsg<'a> {
+    pub channel: &'a str,
+    pub login: Option<&'a str>,
+    pub target_msg_id: &'a str,
+    pub text: Option<&'a str>,
+    pub tags: IrcTags<'a>
+}
+
+/// Settings are only set when they are part of the update.
+#[derive(Debug, Clone)]
+pub struct RoomState<'a> {
+    pub channel: &'a str,
+    pub room_id: Option<&'a str>,
+    pub emote_only: Option<bool>,
+    /// Minutes an account must have followed for, `-1` when disabled.
+    pub followers_only: Option<i64>,
+    pub r9k: Option<bool>,
+    /// Seconds between messages.
+    pub slow: Option<u64>,
+    pub subs_only: Option<bool>,
+    pub tags: IrcTags<'a>
+}
+
+#[derive(Debug, Clone)]
+pub struct UserState<'a> {
+    pub channel: &'a str,
+    pub display_name: Option<Cow<'a, str>>,
+    pub color: Option<&'a str>,
+    pub badges: Vec<Badge<'a>>,
+    pub emote_sets: Vec<&'a str>,
+    pub is_mod: bool,
+    pub tags: IrcTags<'a>
+}
+
+#[derive(Debug, Clone)]
+pub struct GlobalUserState<'a> {
+    pub user_id: Option<&'a str>,
+    pub display_name: Option<Cow<'a, str>>,
+    pub color: Option<&'a str>,
+    pub badges: Vec<Badge<'a>>,
+    pub emote_sets: Vec<&'a str>,
+    pub tags: IrcTags<'a>
+}
+
+#[derive(Debug, Clone)]
+pub struct Notice<'a> {
+    /// Unset for server notices, such as a failed login.
+    pub channel: Option<&'a str>,
+    pub msg_id: Option<&'a str>,
+    pub text: &'a str,
+    pub tags: IrcTags<'a>
+}
+
+#[derive(Debug, Clone)]
+pub struct Whisper<'a> {
+    pub sender_id: Option<&'a str>,
+    pub sender_login: &'a str,
+    pub display_name: Option<Cow<'a, str>>,
+    pub recipient_login: &'a str,
+    pub text: &'a str,
+    pub message_id: Option<&'a str>,
+    pub thread_id: Option<&'a str>,
+    pub badges: Vec<Badge<'a>>,
+    pub emotes: Vec<EmoteRange<'a>>,
+    pub tags: IrcTags<'a>
+}
+
+#[derive(Debug, Clone)]
+pub struct Membership<'a> {
+    pub channel: &'a str,
+    pub login: &'a str
+}
+
+#[derive(Debug, Clone)]
+pub enum TwitchEvent<'a> {
+    PrivMsg(Box<PrivMsg<'a>>),
+    UserNotice(Box<UserNotice<'a>>),
+    ClearChat(ClearChat<'a>),
+    ClearMsg(ClearMsg<'a>),
+    RoomState(RoomState<'a>),
+    UserState(UserState<'a>),
+    GlobalUserState(GlobalUserState<'a>),
+    Notice(Notice<'a>),
+    Whisper(Box<Whisper<'a>>),
+    Join(Membership<'a>),
+    Part(Membership<'a>),
+    /// The server is about to restart, reconnect and rejoin.
+    Reconnect,
+    Other(IrcMessage<'a>)
+}
+
+impl<'a> TwitchEvent<'a> {
+    pub fn from_irc(message: IrcMessage<'a>) -> Result<Self, ParseError> {
+        let tags = message.tags();
+
+        Ok(match message.command() {
+            "PRIVMSG" => {
+                let text = required(message.param(1), "text")?;
+                let action = text.strip_prefix("\u{1}ACTION ").map(|x| x.trim_end_matches('\u{1}'));
+
+                TwitchEvent::PrivMsg(Box::new(PrivMsg {
+                    channel: required(message.channel(), "channel")?,
+                    id: tags.get_nonempty("id"),
+                    sender_id: tags.get_nonempty("user-id"),
+                    sender_login: required(message.nick(), "sender")?,
+                    display_name: tags.get("display-name").filter(|x| !x.is_empty()),
+                    text: action.unwrap_or(text),
+                    is_action: action.is_some(),
+                    badges: parse_badges(tags.get_raw("badges").unwrap_or_default()),
+                    badge_info: parse_badges(tags.get_raw("badge-info").unwrap_or_default()),
+                    emotes: parse_emotes(tags.get_raw("emotes").unwrap_or_default()),
+                    color: tags.get_nonempty("color"),
+                    bits: number(&tags, "bits")?,
+                    reply_parent_msg_id: tags.get_nonempty("reply-parent-msg-id"),
+                    is_first_msg: flag(&tags, "first-msg").unwrap_or(false),
+                    tmi_sent_ts: number(&tags, "tmi-sent-ts")?,
+                    tags
+                }))
+            },
+            "USERNOTICE" => TwitchEvent::UserNotice(Box::new(UserNotice {
+                channel: required(message.channel(), "channel")?,
+                id: tags.get_nonempty("id"),
+                sender_id: tags.get_nonempty("user-id"),
+                sender_login: tags.get_nonempty("login"),
+                display_name: tags.get("display-name").filter(|x| !x.is_empty()),
+                system_msg: tags.get("system-msg"),
+                text: message.param(1),
+                badges: parse_badges(tags.get_raw("badges").unwrap_or_default()),
+                emotes: parse_emotes(tags.get_raw("emotes").unwrap_or_default()),
+                kind: user_notice_kind(&tags)?,
+                tags
+            })),
+            "CLEARCHAT" => TwitchEvent::ClearChat(ClearChat {
+                channel: required(message.channel(), "channel")?,
+                room_id: tags.get_nonempty("room-id"),
+                target_login: message.param(1),
+                target_user_id: tags.get_nonempty("target-user-id"),
+                ban_duration: number(&tags, "ban-duration")?,
+                tags
+            }),
+            "CLEARMSG" => TwitchEvent::ClearMsg(ClearMsg {
+                channel: required(message.channel(), "channel")?,
+                login: tags.get_nonempty("login"),
+                target_msg_id: required(tags.get_nonempty("target-msg-id"), "target-msg-id")?,
+                text: message.param(1),
+                tags
+            }),
+            "ROOMSTATE" => TwitchEvent::RoomState(RoomState {
+                channel: required(message.channel(), "channel")?,
+                room_id: tags.get_nonempty("room-id"),
+                emote_only: flag(&tags, "emote-only"),
+                followers_only: number(&tags, "followers-only")?,
+                r9k: flag(&tags, "r9k"),
+                slow: number(&tags, "slow")?,
+                subs_only: flag(&tags, "subs-only"),
+                tags
+            }),
+            "USERSTATE" => TwitchEvent::UserState(UserState {
+                channel: required(message.channel(), "channel")?,
+                display_name: tags.get("display-name").filter(|x| !x.is_empty()),
+                color: tags.get_nonempty("color"),
+                badges: parse_badges(tags.get_raw("badges").unwrap_or_default()),
+                emote_sets: list(&tags, "emote-sets"),
+                is_mod: flag(&tags, "mod").unwrap_or(false),
+                tags
+            }),
+            "GLOBALUSERSTATE" => TwitchEvent::GlobalUserState(GlobalUserState {
+                user_id: tags.get_nonempty("user-id"),
+                display_name: tags.get("display-name").filter(|x| !x.is_empty()),
+                color: tags.get_nonempty("color"),
+                badges: parse_badges(tags.get_raw("badges").unwrap_or_default()),
+                emote_sets: list(&tags, "emote-sets"),
+                tags
+            }),
+            "NOTICE" => TwitchEvent::Notice(Notice {
+                channel: message.channel(),
+                msg_id: tags.get_nonempty("msg-id"),
+                text: required(message.text(), "text")?,
+                tags
+            }),
+            "WHISPER" => TwitchEvent::Whisper(Box::new(Whisper {
+                sender_id: tags.get_nonempty("user-id"),
+                sender_login: required(message.nick(), "sender")?,
+                display_name: tags.get("display-name").filter(|x| !x.is_empty()),
+                recipient_login: required(message.param(0), "recipient")?,
+                text: required(message.param(1), "text")?,
+                message_id: tags.get_nonempty("message-id"),
+                thread_id: tags.get_nonempty("thread-id"),
+                badges: parse_badges(tags.get_raw("badges").unwrap_or_default()),
+                emotes: parse_emotes(tags.get_raw("emotes").unwrap_or_default()),
+                tags
+            })),
+            "JOIN" => TwitchEvent::Join(Membership {
+                channel: required(message.channel(), "channel")?,
+                login: required(message.nick(), "sender")?
+            }),
+            "PART" => TwitchEvent::Part(Membership {
+                channel: required(message.channel(), "channel")?,
+                login: required(message.nick(), "sender")?
+            }),
+            "RECONNECT" => TwitchEvent::Reconnect,
+            _ => TwitchEvent::Other(message)
+        })
+    }
+}
+
+fn user_notice_kind<'a>(tags: &IrcTags<'a>) -> Result<UserNoticeKind<'a>, ParseError> {
+    let msg_id = required(tags.get_nonempty("msg-id"), "msg-id")?;
+    let plan = || SubPlan::from(tags.get_nonempty("msg-param-sub-plan").unwrap_or_default());
+    let plan_name = || tags.get("msg-param-sub-plan-name");
+
+    Ok(match msg_id {
+        "sub" | "resub" => {
+            let cumulative_months = number(tags, "msg-param-cumulative-months")?.unwrap_or(1);
+            let streak_months = match flag(tags, "msg-param-should-share-streak") {
+                Some(true) => number(tags, "msg-param-streak-months")?,
+                _ => None
+            };
+
+            if msg_id == "sub" {
+                UserNoticeKind::Sub {
+                    cumulative_months,
+                    streak_months,
+                    plan: plan(),
+                    plan_name: plan_name()
+                }
+            } else {
+                UserNoticeKind::Resub {
+                    cumulative_months,
+                    streak_months,
+                    plan: plan(),
+                    plan_name: plan_name()
+                }
+            }
+        },
+        "subgift" | "anonsubgift" => UserNoticeKind::SubGift {
+            recipient_id: required(tags.get_nonempty("msg-param-recipient-id"), "msg-param-recipient-id")?,
+            recipient_login: required(tags.get_nonempty("msg-param-recipient-user-name"), "msg-param-recipient-user-name")?,
+            recipient_display_name: tags.get("msg-param-recipient-display-name"),
+            months: number(tags, "msg-param-months")?,
+            gift_months: number(tags, "msg-param-gift-months")?,
+            plan: plan(),
+            plan_name: plan_name()
+        },
+        "submysterygift" | "anonsubmysterygift" => UserNoticeKind::SubMysteryGift {
+            count: number(tags, "msg-param-mass-gift-count")?.unwrap_or(1),
+            plan: plan()
+        },
+        "raid" => UserNoticeKind::Raid {
+            source_login: required(tags.get_nonempty("msg-param-login"), "msg-param-login")?,
+            source_display_name: tags.get("msg-param-displayName"),
+            viewer_count: number(tags, "msg-param-viewerCount")?.unwrap_or(0)
+        },
+        "ritual" => UserNoticeKind::Ritual {
+            name: required(tags.get_nonempty("msg-param-ritual-name"), "msg-param-ritual-name")?
+        },
+        "announcement" => UserNoticeKind::Announcement {
+            color: tags.get_nonempty("msg-param-color")
+        },
+        other => UserNoticeKind::Other(other)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Captured lines, the same ones benches/irc_parse.rs uses.
+    const PRIVMSG: &str = "@badge-info=subscriber/8;badges=subscriber/6,premium/1;bits=100;client-nonce=bbf5ac0a9a0a5f9d5a1c1e3b8d0f1f3a;color=#1E90FF;display-name=Some\\sUser;emotes=25:0-4,12-16/1902:6-10;first-msg=0;flags=;id=b34ccfc7-4977-403a-8a94-33c6bac34fb8;mod=0;returning-chatter=0;room-id=1337;subscriber=1;tmi-sent-ts=1642696567751;turbo=0;user-id=12345678;user-type= :someuser!someuser@someuser.tmi.twitch.tv PRIVMSG #channel :Kappa Keepo Kappa cheer100\r\n";
+    const USERNOTICE: &str = "@badge-info=subscriber/1;badges=subscriber/0;color=#0000FF;display-name=Gifter;emotes=;flags=;id=e9176cd8-5e22-4684-ad40-ce53c2561c5e;login=gifter;mod=0;msg-id=subgift;msg-param-gift-months=1;msg-param-months=2;msg-param-origin-id=da\\s39\\sa3;msg-param-recipient-display-name=Recipient;msg-param-recipient-id=87654321;msg-param-recipient-user-name=recipient;msg-param-sender-count=5;msg-param-sub-plan-name=Channel\\sSubscription;msg-param-sub-plan=1000;room-id=1337;subscriber=1;system-msg=Gifter\\sgifted\\sa\\sTier\\s1\\ssub\\sto\\sRecipient!;tmi-sent-ts=1642696567751;user-id=12345678;user-type= :tmi.twitch.tv USERNOTICE #channel\r\n";
+
+    fn event(line: &str) -> TwitchEvent<'_> {
+        TwitchEvent::from_irc(IrcMessage::parse(line).unwrap()).unwrap()
+    }
+
+    fn privmsg(line: &str) -> PrivMsg<'_> {
+        match event(line) {
+            TwitchEvent::PrivMsg(privmsg) => *privmsg,
+            event => panic!("unexpected event {:?}", event)
+        }
+    }
+
+    fn user_notice(line: &str) -> UserNotice<'_> {
+        match event(line) {
+            TwitchEvent::UserNotice(notice) => *notice,
+            event => panic!("unexpected event {:?}", event)
+        }
+    }
+
+    fn clear_chat(line: &str) -> ClearChat<'_> {
+        match event(line) {
+            TwitchEvent::ClearChat(clear) => clear,
+            event => panic!("unexpected event {:?}", event)
+        }
+    }
+
+    #[test]
+    fn captured_privmsg() {
+        let privmsg = privmsg(PRIVMSG);
+
+        assert_eq!(privmsg.channel, "channel");
+        assert_eq!(privmsg.sender_login, "someuser");
+        assert_eq!(privmsg.sender_id, Some("12345678"));
+        assert_eq!(privmsg.display_name.as_deref(), Some("Some User"));
+        assert_eq!(privmsg.text, "Kappa Keepo Kappa cheer100");
+        assert!(!privmsg.is_action);
+        assert_eq!(privmsg.bits, Some(100));
+        assert_eq!(privmsg.color, Some("#1E90FF"));
+        assert!(!privmsg.is_first_msg);
+        assert_eq!(privmsg.tmi_sent_ts, Some(1642696567751));
+        assert_eq!(privmsg.badge_info, vec![Badge { name: "subscriber", version: "8" }]);
+        assert_eq!(privmsg.emotes.len(), 3);
+    }
+
+    #[test]
+    fn actions() {
+        let cases = [
+            ("\u{1}ACTION waves\u{1}", "waves", true),
+            ("\u{1}ACTION waves", "waves", true),
+            ("\u{1}ACTION \u{1}", "", true),
+            ("ACTION waves", "ACTION waves", false),
+            ("\u{1}ACTIONwaves\u{1}", "\u{1}ACTIONwaves\u{1}", false)
+        ];
+
+        for (text, stripped, is_action) in cases {
+            let line = format!(":someuser!someuser@someuser.tmi.twitch.tv PRIVMSG #channel :{}", text);
+            let privmsg = privmsg(&line);
+            assert_eq!((privmsg.text, privmsg.is_action), (stripped, is_action), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn badges() {
+        let cases: [(&str, &[(&str, &str)]); 5] = [
+            ("", &[]),
+            ("moderator/1", &[("moderator", "1")]),
+            ("moderator/1,subscriber/12", &[("moderator", "1"), ("subscriber", "12")]),
+            ("predictions/blue-1", &[("predictions", "blue-1")]),
+            ("broken,subscriber/3", &[("subscriber", "3")])
+        ];
+
+        for (tag, expected) in cases {
+            let expected: Vec<_> = expected.iter().map(|&(name, version)| Badge { name, version }).collect();
+            assert_eq!(parse_badges(tag), expected, "{:?}", tag);
+        }
+    }
+
+    #[test]
+    fn emotes() {
+        let range = |id, start, end| EmoteRange { id, start, end };
+        let cases = [
+            ("", vec![]),
+            ("25:0-4", vec![range("25", 0, 4)]),
+            ("25:0-4,12-16/1902:6-10", vec![range("25", 0, 4), range("25", 12, 16), range("1902", 6, 10)]),
+            ("emotesv2_abc:3-7", vec![range("emotesv2_abc", 3, 7)]),
+            ("25:0-x,2-3/1902/88:-1", vec![range("25", 2, 3)])
+        ];
+
+        for (tag, expected) in cases {
+            assert_eq!(parse_emotes(tag), expected, "{:?}", tag);
+        }
+    }
+
+    #[test]
+    fn captured_subgift() {
+        let notice = user_notice(USERNOTICE);
+
+        assert_eq!(notice.channel, "channel");
+        assert_eq!(notice.sender_login, Some("gifter"));
+        assert_eq!(notice.system_msg.as_deref(), Some("Gifter gifted a Tier 1 sub to Recipient!"));
+        assert_eq!(notice.text, None);
+        assert!(notice.emotes.is_empty());
+
+        match notice.kind {
+            UserNoticeKind::SubGift { recipient_id, recipient_login, recipient_display_name, months, gift_months, plan, plan_name } => {
+                assert_eq!(recipient_id, "87654321");
+                assert_eq!(recipient_login, "recipient");
+                assert_eq!(recipient_display_name.as_deref(), Some("Recipient"));
+                assert_eq!(months, Some(2));
+                assert_eq!(gift_months, Some(1));
+                assert_eq!(plan, SubPlan::Tier1);
+                assert_eq!(plan_name.as_deref(), Some("Channel Subscription"));
+            },
+            kind => panic!("unexpected kind {:?}", kind)
+        }
+    }
+
+    #[test]
+    fn user_notice_kinds() {
+        let line = |tags: &str| format!("@login=someuser;{} :tmi.twitch.tv USERNOTICE #channel :Great stream!", tags);
+
+        let notice = line("msg-id=sub;msg-param-cumulative-months=1;msg-param-sub-plan=Prime");
+        match user_notice(&notice).kind {
+            UserNoticeKind::Sub { cumulative_months, streak_months, plan, .. } => {
+                assert_eq!((cumulative_months, streak_months, plan), (1, None, SubPlan::Prime));
+            },
+            kind => panic!("unexpected kind {:?}", kind)
+        }
+
+        let notice = line("msg-id=resub;msg-param-cumulative-months=14;msg-param-should-share-streak=1;msg-param-streak-months=3;msg-param-sub-plan=3000");
+        match user_notice(&notice).kind {
+            UserNoticeKind::Resub { cumulative_months, streak_months, plan, .. } => {
+                assert_eq!((cumulative_months, streak_months, plan), (14, Some(3), SubPlan::Tier3));
+            },
+            kind => panic!("unexpected kind {:?}", kind)
+        }
+
+        let notice = line("msg-id=resub;msg-param-cumulative-months=14;msg-param-should-share-streak=0;msg-param-streak-months=3;msg-param-sub-plan=2000");
+        match user_notice(&notice).kind {
+            UserNoticeKind::Resub { streak_months, plan, .. } => assert_eq!((streak_months, plan), (None, SubPlan::Tier2)),
+            kind => panic!("unexpected kind {:?}", kind)
+        }
+
+        let notice = line("msg-id=anonsubgift;msg-param-recipient-id=1;msg-param-recipient-user-name=recipient;msg-param-sub-plan=1000");
+        match user_notice(&notice).kind {
+            UserNoticeKind::SubGift { recipient_id, months, .. } => assert_eq!((recipient_id, months), ("1", None)),
+            kind => panic!("unexpected kind {:?}", kind)
+        }
+
+        let notice = line("msg-id=raid;msg-param-login=raider;msg-param-displayName=The\\sRaider;msg-param-viewerCount=42");
+        match user_notice(&notice).kind {
+            UserNoticeKind::Raid { source_login, source_display_name, viewer_count } => {
+                assert_eq!(source_login, "raider");
+                assert_eq!(source_display_name.as_deref(), Some("The Raider"));
+                assert_eq!(viewer_count, 42);
+            },
+            kind => panic!("unexpected kind {:?}", kind)
+        }
+
+        let notice = line("msg-id=ritual;msg-param-ritual-name=new_chatter");
+        assert!(matches!(user_notice(&notice).kind, UserNoticeKind::Ritual { name: "new_chatter" }));
+
+        let notice = line("msg-id=bitsbadgetier;msg-param-threshold=1000");
+        assert!(matches!(user_notice(&notice).kind, UserNoticeKind::Other("bitsbadgetier")));
+    }
+
+    #[test]
+    fn invalid_user_notices() {
+        let cases = [
+            ("@login=someuser :tmi.twitch.tv USERNOTICE #channel", ParseError::MissingField("msg-id")),
+            ("@msg-id=raid;msg-param-viewerCount=42 :tmi.twitch.tv USERNOTICE #channel", ParseError::MissingField("msg-param-login")),
+            ("@msg-id=ritual :tmi.twitch.tv USERNOTICE #channel", ParseError::MissingField("msg-param-ritual-name")),
+            ("@msg-id=resub;msg-param-cumulative-months=many :tmi.twitch.tv USERNOTICE #channel", ParseError::InvalidField("msg-param-cumulative-months"))
+        ];
+
+        for (line, error) in cases {
+            assert_eq!(TwitchEvent::from_irc(IrcMessage::parse(line).unwrap()).unwrap_err(), error, "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn clear_chat_kinds() {
+        let timeout = clear_chat("@ban-duration=600;room-id=1337;target-user-id=87654321 :tmi.twitch.tv CLEARCHAT #channel :baduser");
+        assert_eq!(timeout.target_login, Some("baduser"));
+        assert_eq!(timeout.target_user_id, Some("87654321"));
+        assert_eq!(timeout.ban_duration, Some(600));
+
+        let ban = clear_chat("@room-id=1337;target-user-id=87654321 :tmi.twitch.tv CLEARCHAT #channel :baduser");
+        assert_eq!(ban.target_login, Some("baduser"));
+        assert_eq!(ban.ban_duration, None);
+
+        let clear = clear_chat("@room-id=1337 :tmi.twitch.tv CLEARCHAT #channel");
+        assert_eq!(clear.room_id, Some("1337"));
+        assert_eq!(clear.target_login, None);
+        assert_eq!(clear.target_user_id, None);
+
+        let line = "@ban-duration=soon :tmi.twitch.tv CLEARCHAT #channel :baduser";
+        assert_eq!(TwitchEvent::from_irc(IrcMessage::parse(line).unwrap()).unwrap_err(), ParseError::InvalidField("ban-duration"));
+    }
+}
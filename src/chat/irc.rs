@@ -0,0 +1,306 @@
+
+use std::{borrow::Cow, error::Error, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    UnterminatedTags,
+    UnterminatedPrefix,
+    InvalidCommand,
+    /// A parameter or tag a typed event requires is missing.
+    MissingField(&'static str),
+    InvalidField(&'static str)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "Empty IRC message!"),
+            ParseError::UnterminatedTags => write!(f, "IRC message has tags but no command!"),
+            ParseError::UnterminatedPrefix => write!(f, "IRC message has a prefix but no command!"),
+            ParseError::InvalidCommand => write!(f, "Invalid IRC command!"),
+            ParseError::MissingField(field) => write!(f, "Missing {} in IRC message!", field),
+            ParseError::InvalidField(field) => write!(f, "Invalid {} in IRC message!", field)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// IRCv3 tags, looked up in place and only unescaped when a value is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IrcTags<'a>(&'a str);
+
+impl<'a> IrcTags<'a> {
+    /// Iterates over the keys and still escaped values.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.0.split(';')
+            .filter(|x| !x.is_empty())
+            .map(|x| x.split_once('=').unwrap_or((x, "")))
+    }
+
+    pub fn get_raw(&self, key: &str) -> Option<&'a str> {
+        self.iter().find(|(name, _)| *name == key).map(|(_, value)| value)
+    }
+
+    pub fn get(&self, key: &str) -> Option<Cow<'a, str>> {
+        self.get_raw(key).map(unescape_tag)
+    }
+
+    /// Unset and empty values are both `None`.
+    pub fn get_nonempty(&self, key: &str) -> Option<&'a str> {
+        self.get_raw(key).filter(|x| !x.is_empty())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A single IRC line, borrowed from the buffer it was read into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IrcMessage<'a> {
+    raw: &'a str,
+    tags: IrcTags<'a>,
+    prefix: Option<&'a str>,
+    command: &'a str,
+    params: &'a str
+}
+
+impl<'a> IrcMessage<'a> {
+    /// Parses without allocating, any input either parses or errors without panicking.
+    pub fn parse(line: &'a str) -> Result<Self, ParseError> {
+        let raw = line.trim_end_matches(['\r', '\n']);
+        let mut rest = raw;
+        let mut tags = IrcTags::default();
+        let mut prefix = None;
+
+        if rest.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        if let Some(tagged) = rest.strip_prefix('@') {
+            let (raw_tags, remainder) = tagged.split_once(' ').ok_or(ParseError::UnterminatedTags)?;
+            tags = IrcTags(raw_tags);
+            rest = remainder.trim_start_matches(' ');
+        }
+
+        if let Some(prefixed) = rest.strip_prefix(':') {
+            let (source, remainder) = prefixed.split_once(' ').ok_or(ParseError::UnterminatedPrefix)?;
+            prefix = Some(source);
+            rest = remainder.trim_start_matches(' ');
+        }
+
+        let (command, params) = rest.split_once(' ').unwrap_or((rest, ""));
+        if command.is_empty() || !command.bytes().all(|x| x.is_ascii_alphanumeric()) {
+            return Err(ParseError::InvalidCommand);
+        }
+
+        Ok(Self {
+            raw,
+            tags,
+            prefix,
+            command,
+            params
+        })
+    }
+
+    pub fn raw(&self) -> &'a str {
+        self.raw
+    }
+
+    pub fn tags(&self) -> IrcTags<'a> {
+        self.tags
+    }
+
+    pub fn tag(&self, key: &str) -> Option<Cow<'a, str>> {
+        self.tags.get(key)
+    }
+
+    pub fn prefix(&self) -> Option<&'a str> {
+        self.prefix
+    }
+
+    pub fn command(&self) -> &'a str {
+        self.command
+    }
+
+    /// The nickname of the sender, from a `nick!user@host` prefix.
+    pub fn nick(&self) -> Option<&'a str> {
+        self.prefix
+            .and_then(|x| x.split_once('!'))
+            .map(|(nick, _)| nick)
+    }
+
+    pub fn params(&self) -> Params<'a> {
+        Params(self.params)
+    }
+
+    pub fn param(&self, index: usize) -> Option<&'a str> {
+        self.params().nth(index)
+    }
+
+    /// The channel this message targets, without the leading `#`.
+    pub fn channel(&self) -> Option<&'a str> {
+        self.params().next().and_then(|x| x.strip_prefix('#'))
+    }
+
+    /// The last parameter, which holds the text of `PRIVMSG`, `NOTICE` and similar commands.
+    pub fn text(&self) -> Option<&'a str> {
+        self.params().last()
+    }
+}
+
+/// Iterator over the parameters of a message, the trailing one included.
+#[derive(Debug, Clone)]
+pub struct Params<'a>(&'a str);
+
+impl<'a> Iterator for Params<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let rest = self.0.trim_start_matches(' ');
+        if rest.is_empty() {
+            self.0 = rest;
+            return None;
+        }
+
+        if let Some(trailing) = rest.strip_prefix(':') {
+            self.0 = "";
+            return Some(trailing);
+        }
+
+        let (param, remainder) = rest.split_once(' ').unwrap_or((rest, ""));
+        self.0 = remainder;
+        Some(param)
+    }
+}
+
+fn unescape_tag(value: &str) -> Cow<'_, str> {
+    if !value.contains('\\') {
+        return Cow::Borrowed(value);
+    }
+
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some(':') => unescaped.push(';'),
+            Some('s') => unescaped.push(' '),
+            Some('r') => unescaped.push('\r'),
+            Some('n') => unescaped.push('\n'),
+            Some(c) => unescaped.push(c),
+            None => {}
+        }
+    }
+
+    Cow::Owned(unescaped)
+}
+
+/// An owned line that is known to parse, for passing messages between tasks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatLine(String);
+
+impl ChatLine {
+    pub fn new(line: String) -> Result<Self, ParseError> {
+        IrcMessage::parse(&line)?;
+        Ok(Self(line))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn message(&self) -> IrcMessage<'_> {
+        IrcMessage::parse(&self.0).expect("chat line was validated on creation")
+    }
+
+    pub fn event(&self) -> Result<super::TwitchEvent<'_>, ParseError> {
+        super::TwitchEvent::from_irc(self.message())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unescape() {
+        let cases = [
+            ("plain", "plain"),
+            ("", ""),
+            ("Some\\sUser", "Some User"),
+            ("a\\:b", "a;b"),
+            ("back\\\\slash", "back\\slash"),
+            ("line\\rbreak\\n", "line\rbreak\n"),
+            ("unknown\\x", "unknownx"),
+            ("trailing\\", "trailing"),
+            ("\\", ""),
+            ("\\s\\s", "  ")
+        ];
+
+        for (escaped, unescaped) in cases {
+            assert_eq!(unescape_tag(escaped), unescaped, "{:?}", escaped);
+        }
+
+        assert!(matches!(unescape_tag("plain"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn invalid_lines() {
+        let cases = [
+            ("", ParseError::Empty),
+            ("\r\n", ParseError::Empty),
+            ("@badges=", ParseError::UnterminatedTags),
+            ("@badges= ", ParseError::InvalidCommand),
+            (":tmi.twitch.tv", ParseError::UnterminatedPrefix),
+            ("@badges= :tmi.twitch.tv", ParseError::UnterminatedPrefix),
+            (":tmi.twitch.tv ", ParseError::InvalidCommand),
+            (":tmi.twitch.tv :text", ParseError::InvalidCommand),
+            ("PRIV-MSG #channel", ParseError::InvalidCommand)
+        ];
+
+        for (line, error) in cases {
+            assert_eq!(IrcMessage::parse(line), Err(error), "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn valid_lines() {
+        let message = IrcMessage::parse("PING :tmi.twitch.tv\r\n").unwrap();
+        assert_eq!(message.raw(), "PING :tmi.twitch.tv");
+        assert!(message.tags().is_empty());
+        assert_eq!(message.prefix(), None);
+        assert_eq!(message.command(), "PING");
+        assert_eq!(message.text(), Some("tmi.twitch.tv"));
+
+        let message = IrcMessage::parse(":tmi.twitch.tv 001 justinfan123 :Welcome, GLHF!").unwrap();
+        assert_eq!(message.prefix(), Some("tmi.twitch.tv"));
+        assert_eq!(message.nick(), None);
+        assert_eq!(message.command(), "001");
+        assert_eq!(message.params().collect::<Vec<_>>(), vec!["justinfan123", "Welcome, GLHF!"]);
+
+        let message = IrcMessage::parse("@flags=;display-name=Some\\sUser;turbo  :someuser!someuser@someuser.tmi.twitch.tv  PRIVMSG #channel :hi :) there").unwrap();
+        assert_eq!(message.tags().get_raw("flags"), Some(""));
+        assert_eq!(message.tags().get_nonempty("flags"), None);
+        assert_eq!(message.tags().get_raw("turbo"), Some(""));
+        assert_eq!(message.tag("display-name").as_deref(), Some("Some User"));
+        assert_eq!(message.tag("missing"), None);
+        assert_eq!(message.nick(), Some("someuser"));
+        assert_eq!(message.channel(), Some("channel"));
+        assert_eq!(message.text(), Some("hi :) there"));
+    }
+
+    #[test]
+    fn chat_line() {
+        let line = ChatLine::new(String::from("RECONNECT")).unwrap();
+        assert_eq!(line.message().command(), "RECONNECT");
+        assert!(ChatLine::new(String::new()).is_err());
+    }
+}
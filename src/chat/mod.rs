@@ -0,0 +1,15 @@
+
+mod irc;
+pub use irc::*;
+
+mod events;
+pub use events::*;
+
+mod connection;
+pub use connection::ChatTransport;
+
+mod client;
+pub use client::*;
+
+mod pool;
+pub use pool::*;
@@ -0,0 +1,441 @@
+
+use std::{collections::{HashMap, HashSet}, error::Error, sync::{Arc, Mutex}, time::Duration};
+use futures::Stream;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use crate::{TwitchClient, util::RateLimiter};
+use super::{ChatClient, ChatConfig, ChatEvent, ChatLine, client::{normalize_channel, validate_parent_id, validate_text}};
+
+/// How long queued messages wait for the rejoins to be confirmed, a channel that cannot be joined never is.
+const REJOIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone)]
+pub enum PoolEvent {
+    Message {
+        shard: usize,
+        line: ChatLine
+    },
+    Connected {
+        shard: usize
+    },
+    /// A connection dropped, it is reconnected and its channels rejoined after `retry_in`.
+    /// Messages sent meanwhile are queued and delivered once the server confirms the rejoins, or after 10 seconds.
+    Disconnected {
+        shard: usize,
+        reason: Option<String>,
+        retry_in: Duration
+    }
+}
+
+enum ShardCommand {
+    Join(String),
+    Part(String),
+    Raw(String)
+}
+
+struct Shard {
+    commands: UnboundedSender<ShardCommand>,
+    channels: usize
+}
+
+#[derive(Default)]
+struct PoolState {
+    shards: Vec<Shard>,
+    channels: HashMap<String, usize>
+}
+
+/// Settings every shard task needs to (re)connect.
+#[derive(Clone)]
+struct ShardContext {
+    config: ChatConfig,
+    login: String,
+    token: Arc<Mutex<Option<String>>>,
+    backoff: (Duration, Duration),
+    join_limiter: Arc<RateLimiter>,
+    events: UnboundedSender<PoolEvent>
+}
+
+/// Spreads joined channels over as many chat connections as needed, reconnecting and rejoining them on their own.
+pub struct ChatPool {
+    context: ShardContext,
+    channels_per_connection: usize,
+    max_connections: Option<usize>,
+    message_limiter: RateLimiter,
+    state: Mutex<PoolState>,
+    events: UnboundedReceiver<PoolEvent>
+}
+
+impl ChatPool {
+    /// Uses the access token of the client's auth provider, see `update_token` to replace it once it expires.
+    pub async fn new(client: &TwitchClient, config: ChatConfig) -> Result<Self, Box<dyn Error>> {
+        let login = config.login.clone()
+            .ok_or_else(|| String::from("Chat login is required!"))?;
+        let token = client.access_token().await?;
+
+        Ok(Self::create(config, login, Some(token)))
+    }
+
+    pub fn anonymous(config: ChatConfig) -> Self {
+        Self::create(config, ChatClient::anonymous_login(), None)
+    }
+
+    fn create(config: ChatConfig, login: String, token: Option<String>) -> Self {
+        let (events_sender, events) = unbounded_channel();
+
+        Self {
+            message_limiter: RateLimiter::new(vec![config.message_limit]),
+            context: ShardContext {
+                join_limiter: Arc::new(RateLimiter::new(vec![config.join_limit])),
+                config,
+                login,
+                token: Arc::new(Mutex::new(token)),
+                backoff: (Duration::from_secs(1), Duration::from_secs(60)),
+                events: events_sender
+            },
+            channels_per_connection: 100,
+            max_connections: None,
+            state: Mutex::new(PoolState::default()),
+            events
+        }
+    }
+
+    /// Defaults to 100.
+    pub fn with_channels_per_connection(mut self, channels_per_connection: usize) -> Self {
+        self.channels_per_connection = channels_per_connection.max(1);
+        self
+    }
+
+    /// Unlimited by default, joins fail once every connection is full.
+    pub fn with_max_connections(mut self, max_connections: Option<usize>) -> Self {
+        self.max_connections = max_connections;
+        self
+    }
+
+    /// Reconnect delay, doubled after every failed attempt up to `max`. Defaults to 1 and 60 seconds.
+    pub fn with_reconnect_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.context.backoff = (initial, max.max(initial));
+        self
+    }
+
+    /// Used by every following reconnect.
+    pub fn update_token<S: Into<String>>(&self, token: S) {
+        *self.context.token.lock().unwrap() = Some(token.into());
+    }
+
+    pub fn channels(&self) -> Vec<String> {
+        self.state.lock().unwrap().channels.keys().cloned().collect()
+    }
+
+    pub fn connections(&self) -> usize {
+        self.state.lock().unwrap().shards.len()
+    }
+
+    /// Messages from every connection, merged.
+    pub fn events(&mut self) -> impl Stream<Item = PoolEvent> + '_ {
+        &mut self.events
+    }
+
+    pub async fn join<S: AsRef<str>>(&self, channel: S) -> Result<(), Box<dyn Error>> {
+        let channel = normalize_channel(channel.as_ref())?;
+        if self.state.lock().unwrap().channels.contains_key(&channel) {
+            return Ok(());
+        }
+
+        self.context.join_limiter.acquire().await;
+
+        let mut state = self.state.lock().unwrap();
+        if state.channels.contains_key(&channel) {
+            return Ok(());
+        }
+
+        let shard = match state.shards.iter().position(|x| x.channels < self.channels_per_connection) {
+            Some(shard) => shard,
+            None => {
+                if self.max_connections.is_some_and(|x| state.shards.len() >= x) {
+                    return Err(String::from("Every chat connection is full!").into());
+                }
+
+                let (commands, receiver) = unbounded_channel();
+                tokio::spawn(run_shard(state.shards.len(), self.context.clone(), receiver));
+                state.shards.push(Shard {
+                    commands,
+                    channels: 0
+                });
+                state.shards.len() - 1
+            }
+        };
+
+        state.shards[shard].commands.send(ShardCommand::Join(channel.clone()))
+            .map_err(|_| String::from("Chat connection is closed!"))?;
+        state.shards[shard].channels += 1;
+        state.channels.insert(channel, shard);
+
+        Ok(())
+    }
+
+    pub async fn part<S: AsRef<str>>(&self, channel: S) -> Result<(), Box<dyn Error>> {
+        let channel = normalize_channel(channel.as_ref())?;
+
+        let mut state = self.state.lock().unwrap();
+        if let Some(shard) = state.channels.remove(&channel) {
+            state.shards[shard].channels -= 1;
+            let _ = state.shards[shard].commands.send(ShardCommand::Part(channel));
+        }
+
+        Ok(())
+    }
+
+    pub async fn send_message<S: AsRef<str>, T: AsRef<str>>(&self, channel: S, text: T) -> Result<(), Box<dyn Error>> {
+        let channel = normalize_channel(channel.as_ref())?;
+        let text = validate_text(text.as_ref())?;
+        self.message_limiter.acquire().await;
+        self.send_to(&channel, format!("PRIVMSG #{} :{}", channel, text))
+    }
+
+    /// Replies to the message with the given `id` tag.
+    pub async fn reply<S: AsRef<str>, T: AsRef<str>, U: AsRef<str>>(&self, channel: S, parent_id: T, text: U) -> Result<(), Box<dyn Error>> {
+        let channel = normalize_channel(channel.as_ref())?;
+        let parent_id = validate_parent_id(parent_id.as_ref())?;
+        let text = validate_text(text.as_ref())?;
+        self.message_limiter.acquire().await;
+        self.send_to(&channel, format!("@reply-parent-msg-id={} PRIVMSG #{} :{}", parent_id, channel, text))
+    }
+
+    fn send_to(&self, channel: &str, line: String) -> Result<(), Box<dyn Error>> {
+        let state = self.state.lock().unwrap();
+        let shard = state.channels.get(channel)
+            .ok_or_else(|| String::from("Channel is not joined!"))?;
+
+        state.shards[*shard].commands.send(ShardCommand::Raw(line))
+            .map_err(|_| String::from("Chat connection is closed!").into())
+    }
+}
+
+enum ShardStep {
+    Event(Option<ChatEvent>),
+    Command(Option<ShardCommand>),
+    RejoinTimeout
+}
+
+/// Keeps one connection alive until the pool is dropped, rejoining its channels after every reconnect.
+async fn run_shard(shard: usize, context: ShardContext, mut commands: UnboundedReceiver<ShardCommand>) {
+    let mut channels = HashSet::new();
+    let mut pending = Vec::new();
+    let mut backoff = context.backoff.0;
+
+    loop {
+        let token = context.token.lock().unwrap().clone();
+        let reason = match ChatClient::open(context.config.clone(), context.login.clone(), token).await.map_err(|x| x.to_string()) {
+            Ok(mut client) => {
+                backoff = context.backoff.0;
+                let _ = context.events.send(PoolEvent::Connected { shard });
+
+                for channel in &channels {
+                    context.join_limiter.acquire().await;
+                    let _ = client.send_raw(format!("JOIN #{}", channel));
+                }
+
+                // Queued messages would be dropped by the server if sent before the channels are joined again.
+                let mut rejoining = channels.clone();
+                let rejoin_timeout = tokio::time::delay_for(REJOIN_TIMEOUT);
+                tokio::pin!(rejoin_timeout);
+
+                loop {
+                    if rejoining.is_empty() {
+                        for line in pending.drain(..) {
+                            let _ = client.send_raw(line);
+                        }
+                    }
+
+                    let step = tokio::select! {
+                        event = client.next_event() => ShardStep::Event(event),
+                        command = commands.recv() => ShardStep::Command(command),
+                        _ = &mut rejoin_timeout, if !rejoining.is_empty() => ShardStep::RejoinTimeout
+                    };
+
+                    match step {
+                        ShardStep::Event(Some(ChatEvent::Message(line))) => {
+                            let message = line.message();
+                            if message.command() == "JOIN" && message.nick() == Some(context.login.as_str()) {
+                                if let Some(channel) = message.channel() {
+                                    rejoining.remove(channel);
+                                }
+                            }
+
+                            let reconnect = message.command() == "RECONNECT";
+                            if context.events.send(PoolEvent::Message { shard, line }).is_err() {
+                                return;
+                            }
+
+                            if reconnect {
+                                break Some(String::from("Server requested a reconnect"));
+                            }
+                        },
+                        ShardStep::Event(Some(ChatEvent::Disconnected(reason))) => break reason,
+                        ShardStep::Event(None) => break None,
+                        ShardStep::Command(Some(ShardCommand::Raw(line))) if !pending.is_empty() => pending.push(line),
+                        ShardStep::Command(Some(command)) => {
+                            let raw = matches!(command, ShardCommand::Raw(_));
+                            let line = apply_command(&mut channels, command);
+                            rejoining.retain(|x| channels.contains(x));
+                            if client.send_raw(line.clone()).is_err() && raw {
+                                pending.push(line);
+                            }
+                        },
+                        ShardStep::RejoinTimeout => rejoining.clear(),
+                        ShardStep::Command(None) => return
+                    }
+                }
+            },
+            Err(error) => Some(error)
+        };
+
+        if context.events.send(PoolEvent::Disconnected { shard, reason, retry_in: backoff }).is_err() {
+            return;
+        }
+
+        let delay = tokio::time::delay_for(backoff);
+        tokio::pin!(delay);
+        loop {
+            tokio::select! {
+                _ = &mut delay => break,
+                command = commands.recv() => match command {
+                    Some(command) => hold_command(&mut channels, &mut pending, command),
+                    None => return
+                }
+            }
+        }
+
+        backoff = (backoff * 2).min(context.backoff.1);
+    }
+}
+
+/// Tracks joined channels and returns the line to send for a command.
+fn apply_command(channels: &mut HashSet<String>, command: ShardCommand) -> String {
+    match command {
+        ShardCommand::Join(channel) => {
+            let line = format!("JOIN #{}", channel);
+            channels.insert(channel);
+            line
+        },
+        ShardCommand::Part(channel) => {
+            let line = format!("PART #{}", channel);
+            channels.remove(&channel);
+            line
+        },
+        ShardCommand::Raw(line) => line
+    }
+}
+
+/// Applies a command while disconnected, joins and parts are covered by the rejoin and other lines wait for it.
+fn hold_command(channels: &mut HashSet<String>, pending: &mut Vec<String>, command: ShardCommand) {
+    match command {
+        ShardCommand::Raw(line) => pending.push(line),
+        command => {
+            apply_command(channels, command);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use futures::StreamExt;
+    use tokio::{io::{split, AsyncBufReadExt, AsyncWriteExt, BufReader, Lines, ReadHalf, WriteHalf}, net::{TcpListener, TcpStream}};
+    use crate::chat::ChatTransport;
+    use super::*;
+
+    type ServerLines = Lines<BufReader<ReadHalf<TcpStream>>>;
+
+    /// Accepts an anonymous login and waits for the shard to join `#foo`.
+    async fn accept_shard(listener: &mut TcpListener) -> (ServerLines, WriteHalf<TcpStream>, String) {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (reader, mut writer) = split(stream);
+        let mut lines = BufReader::new(reader).lines();
+
+        let mut login = String::new();
+        while let Some(line) = lines.next_line().await.unwrap() {
+            if let Some(nick) = line.strip_prefix("NICK ") {
+                login = nick.to_owned();
+                break;
+            }
+        }
+
+        writer.write_all(format!(":tmi.twitch.tv 001 {} :Welcome, GLHF!\r\n", login).as_bytes()).await.unwrap();
+        assert_eq!(lines.next_line().await.unwrap().unwrap(), "JOIN #foo");
+
+        (lines, writer, login)
+    }
+
+    async fn echo_join(writer: &mut WriteHalf<TcpStream>, login: &str) {
+        writer.write_all(format!(":{0}!{0}@{0}.tmi.twitch.tv JOIN #foo\r\n", login).as_bytes()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn reconnects_rejoins_and_flushes() {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let transport = ChatTransport::Tcp {
+            host: String::from("127.0.0.1"),
+            port: listener.local_addr().unwrap().port()
+        };
+
+        let server = tokio::spawn(async move {
+            let (_lines, mut writer, login) = accept_shard(&mut listener).await;
+            echo_join(&mut writer, &login).await;
+            writer.write_all(b":tmi.twitch.tv RECONNECT\r\n").await.unwrap();
+
+            let (mut lines, mut writer, login) = accept_shard(&mut listener).await;
+
+            // Nothing queued may arrive before the rejoin is confirmed.
+            assert!(tokio::time::timeout(Duration::from_millis(100), lines.next_line()).await.is_err());
+            echo_join(&mut writer, &login).await;
+            assert_eq!(lines.next_line().await.unwrap().unwrap(), "PRIVMSG #foo :hello");
+        });
+
+        let mut pool = ChatPool::anonymous(ChatConfig::new().with_transport(transport))
+            .with_reconnect_backoff(Duration::from_millis(200), Duration::from_secs(1));
+        pool.join("foo").await.unwrap();
+
+        loop {
+            match pool.events().next().await.unwrap() {
+                PoolEvent::Disconnected { shard, reason, retry_in } => {
+                    assert_eq!(shard, 0);
+                    assert_eq!(reason.as_deref(), Some("Server requested a reconnect"));
+                    assert_eq!(retry_in, Duration::from_millis(200));
+                    break;
+                },
+                PoolEvent::Connected { .. } | PoolEvent::Message { .. } => {}
+            }
+        }
+
+        pool.send_message("foo", "hello").await.unwrap();
+        server.await.unwrap();
+        assert_eq!(pool.channels(), vec![String::from("foo")]);
+    }
+
+    #[test]
+    fn holds_lines_while_disconnected() {
+        let mut channels = HashSet::new();
+        let mut pending = Vec::new();
+
+        hold_command(&mut channels, &mut pending, ShardCommand::Join(String::from("foo")));
+        hold_command(&mut channels, &mut pending, ShardCommand::Raw(String::from("PRIVMSG #foo :hello")));
+        hold_command(&mut channels, &mut pending, ShardCommand::Join(String::from("bar")));
+        hold_command(&mut channels, &mut pending, ShardCommand::Part(String::from("foo")));
+        hold_command(&mut channels, &mut pending, ShardCommand::Raw(String::from("PRIVMSG #bar :world")));
+
+        assert_eq!(channels, vec![String::from("bar")].into_iter().collect());
+        assert_eq!(pending, vec!["PRIVMSG #foo :hello", "PRIVMSG #bar :world"]);
+    }
+
+    #[test]
+    fn applies_commands() {
+        let mut channels = HashSet::new();
+
+        assert_eq!(apply_command(&mut channels, ShardCommand::Join(String::from("foo"))), "JOIN #foo");
+        assert!(channels.contains("foo"));
+        assert_eq!(apply_command(&mut channels, ShardCommand::Raw(String::from("PING"))), "PING");
+        assert_eq!(apply_command(&mut channels, ShardCommand::Part(String::from("foo"))), "PART #foo");
+        assert!(channels.is_empty());
+    }
+}
@@ -1,15 +1,57 @@
 
 use std::{cell::RefCell, error::Error, rc::Rc};
-use reqwest::{Client, Method};
+use reqwest::{Client, Method, RequestBuilder, Response};
 use serde::{de::DeserializeOwned, Serialize};
 
 pub use crate::auth::*;
-use crate::api::ApiEndpoint;
+use crate::api::{ApiEndpoint, ApiError};
 
 #[cfg(feature = "clips")]
 use crate::api::helix::clips::ClipsGroup;
 #[cfg(feature = "search")]
 use crate::api::helix::search::SearchGroup;
+#[cfg(feature = "channels")]
+use crate::api::helix::channels::ChannelsGroup;
+#[cfg(feature = "moderation")]
+use crate::api::helix::moderation::ModerationGroup;
+#[cfg(feature = "polls")]
+use crate::api::helix::polls::PollsGroup;
+#[cfg(feature = "predictions")]
+use crate::api::helix::predictions::PredictionsGroup;
+#[cfg(feature = "channel-points")]
+use crate::api::helix::channel_points::ChannelPointsGroup;
+#[cfg(feature = "subscriptions")]
+use crate::api::helix::subscriptions::SubscriptionsGroup;
+#[cfg(feature = "bits")]
+use crate::api::helix::bits::BitsGroup;
+#[cfg(feature = "chat")]
+use crate::api::helix::chat::ChatGroup;
+#[cfg(feature = "schedule")]
+use crate::api::helix::schedule::ScheduleGroup;
+#[cfg(feature = "raids")]
+use crate::api::helix::raids::RaidsGroup;
+#[cfg(feature = "ads")]
+use crate::api::helix::ads::AdsGroup;
+#[cfg(feature = "hype-train")]
+use crate::api::helix::hype_train::HypeTrainGroup;
+#[cfg(feature = "teams")]
+use crate::api::helix::teams::TeamsGroup;
+#[cfg(feature = "goals")]
+use crate::api::helix::goals::GoalsGroup;
+#[cfg(feature = "charity")]
+use crate::api::helix::charity::CharityGroup;
+#[cfg(feature = "guest-star")]
+use crate::api::helix::guest_star::GuestStarGroup;
+#[cfg(feature = "whispers")]
+use crate::api::helix::whispers::WhispersGroup;
+#[cfg(feature = "entitlements")]
+use crate::api::helix::entitlements::EntitlementsGroup;
+#[cfg(feature = "analytics")]
+use crate::api::helix::analytics::AnalyticsGroup;
+#[cfg(feature = "eventsub")]
+use crate::api::helix::eventsub::EventSubGroup;
+#[cfg(feature = "extensions")]
+use crate::api::helix::extensions::{ExtensionsGroup, ExtensionJwtSigner};
 
 #[derive(Default)]
 pub struct TwitchClientBuilder {
@@ -17,7 +59,9 @@ pub struct TwitchClientBuilder {
     client_id: Option<String>,
     access_token: Option<String>,
     client_secret: Option<String>,
-    scopes: Option<Vec<String>>
+    scopes: Option<Vec<String>>,
+    #[cfg(feature = "extensions")]
+    extension_secret: Option<(String, String)>
 }
 
 impl TwitchClientBuilder {
@@ -50,10 +94,23 @@ impl TwitchClientBuilder {
         self
     }
 
+    /// Base64 encoded extension secret and the extension owner's user ID, used to sign extension JWTs.
+    #[cfg(feature = "extensions")]
+    pub fn with_extension_secret<S: Into<String>>(mut self, secret: S, owner_id: S) -> Self {
+        self.extension_secret = Some((secret.into(), owner_id.into()));
+        self
+    }
+
     pub async fn build(self) -> Result<TwitchClient, Box<dyn Error>> {
         let mut auth_provider: Option<Box<dyn AuthProvider>> = None;
         let mut access_token = AccessToken::default();
 
+        #[cfg(feature = "extensions")]
+        let extension_signer = match (&self.client_id, self.extension_secret) {
+            (Some(client_id), Some((secret, owner_id))) => Some(ExtensionJwtSigner::new(client_id.clone(), &secret, owner_id)?),
+            _ => None
+        };
+
         if let Some(client_id) = self.client_id {
             if let Some(access_token) = self.access_token {
                 auth_provider = Some(Box::new(StaticAuthProvider::new(client_id, access_token)));
@@ -81,7 +138,49 @@ impl TwitchClientBuilder {
             #[cfg(feature = "clips")]
             clips: ClipsGroup::new(Rc::clone(&inner)),
             #[cfg(feature = "search")]
-            search: SearchGroup::new(Rc::clone(&inner))
+            search: SearchGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "channels")]
+            channels: ChannelsGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "moderation")]
+            moderation: ModerationGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "polls")]
+            polls: PollsGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "predictions")]
+            predictions: PredictionsGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "channel-points")]
+            channel_points: ChannelPointsGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "subscriptions")]
+            subscriptions: SubscriptionsGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "bits")]
+            bits: BitsGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "chat")]
+            chat: ChatGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "schedule")]
+            schedule: ScheduleGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "raids")]
+            raids: RaidsGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "ads")]
+            ads: AdsGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "hype-train")]
+            hype_train: HypeTrainGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "teams")]
+            teams: TeamsGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "goals")]
+            goals: GoalsGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "charity")]
+            charity: CharityGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "guest-star")]
+            guest_star: GuestStarGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "whispers")]
+            whispers: WhispersGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "extensions")]
+            extensions: ExtensionsGroup::new(Rc::clone(&inner), extension_signer),
+            #[cfg(feature = "entitlements")]
+            entitlements: EntitlementsGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "analytics")]
+            analytics: AnalyticsGroup::new(Rc::clone(&inner)),
+            #[cfg(feature = "eventsub")]
+            eventsub: EventSubGroup::new(Rc::clone(&inner))
         })
     }
 }
@@ -99,42 +198,119 @@ pub struct TwitchClient {
     pub clips: ClipsGroup,
 
     #[cfg(feature = "search")]
-    pub search: SearchGroup
+    pub search: SearchGroup,
+
+    #[cfg(feature = "channels")]
+    pub channels: ChannelsGroup,
+
+    #[cfg(feature = "moderation")]
+    pub moderation: ModerationGroup,
+
+    #[cfg(feature = "polls")]
+    pub polls: PollsGroup,
+
+    #[cfg(feature = "predictions")]
+    pub predictions: PredictionsGroup,
+
+    #[cfg(feature = "channel-points")]
+    pub channel_points: ChannelPointsGroup,
+
+    #[cfg(feature = "subscriptions")]
+    pub subscriptions: SubscriptionsGroup,
+
+    #[cfg(feature = "bits")]
+    pub bits: BitsGroup,
+
+    #[cfg(feature = "chat")]
+    pub chat: ChatGroup,
+
+    #[cfg(feature = "schedule")]
+    pub schedule: ScheduleGroup,
+
+    #[cfg(feature = "raids")]
+    pub raids: RaidsGroup,
+
+    #[cfg(feature = "ads")]
+    pub ads: AdsGroup,
+
+    #[cfg(feature = "hype-train")]
+    pub hype_train: HypeTrainGroup,
+
+    #[cfg(feature = "teams")]
+    pub teams: TeamsGroup,
+
+    #[cfg(feature = "goals")]
+    pub goals: GoalsGroup,
+
+    #[cfg(feature = "charity")]
+    pub charity: CharityGroup,
+    #[cfg(feature = "guest-star")]
+    pub guest_star: GuestStarGroup,
+
+    #[cfg(feature = "whispers")]
+    pub whispers: WhispersGroup,
+
+    #[cfg(feature = "extensions")]
+    pub extensions: ExtensionsGroup,
+
+    #[cfg(feature = "entitlements")]
+    pub entitlements: EntitlementsGroup,
+
+    #[cfg(feature = "analytics")]
+    pub analytics: AnalyticsGroup,
+
+    #[cfg(feature = "eventsub")]
+    pub eventsub: EventSubGroup
 }
 
 impl TwitchClientInner {
-    pub(crate) async fn call<T: DeserializeOwned, S: Into<String>, Q: Serialize + ?Sized>(&self, endpoint: ApiEndpoint, method: Method, scopes: Option<Vec<String>>, url: S, query: &Q) -> Result<T, Box<dyn Error>> {
+    fn build<S: Into<String>, Q: Serialize + ?Sized, B: Serialize + ?Sized>(&self, endpoint: ApiEndpoint, method: Method, url: S, query: &Q, accept: &str, body: Option<&B>) -> Result<RequestBuilder, Box<dyn Error>> {
         let mut request = self.client
             .request(method, endpoint.get_endpoint(&url.into())?)
-            .header("Accept", "application/json")
+            .header("Accept", accept)
             .query(query);
 
-        //println!("{:?}", &request);
-        
-        if let Some(auth_provider) = &self.auth_provider {
-            if let Some(ref client_id) = auth_provider.get_client_id() {
-                request = request.header("Client-Id", client_id);
-            }
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+
+        Ok(request)
+    }
 
-            if auth_provider.can_refresh() {
-                let mut needs_refresh = false;
-                {
-                    let access_token = &*self.access_token.borrow();
+    async fn refresh(&self, auth_provider: &dyn AuthProvider, scopes: Option<Vec<String>>) -> Result<(), Box<dyn Error>> {
+        if auth_provider.can_refresh() {
+            let mut needs_refresh = false;
+            {
+                let access_token = &*self.access_token.borrow();
 
-                    if access_token.refresh_token.is_some() {
-                        if let Some(ref time) = access_token.time {
-                            if let Some(ref expires_in) = access_token.expires_in {
-                                needs_refresh = &time.elapsed() >= expires_in;
-                            }
+                if access_token.refresh_token.is_some() {
+                    if let Some(ref time) = access_token.time {
+                        if let Some(ref expires_in) = access_token.expires_in {
+                            needs_refresh = &time.elapsed() >= expires_in;
                         }
                     }
                 }
+            }
 
-                if needs_refresh {
-                    auth_provider.refresh_token(&mut *self.access_token.borrow_mut(), scopes).await?;
-                }
+            if needs_refresh {
+                let mut access_token = self.access_token.take();
+                let result = auth_provider.refresh_token(&mut access_token, scopes).await;
+                self.access_token.replace(access_token);
+                result?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn authorize(&self, mut request: RequestBuilder, scopes: Option<Vec<String>>) -> Result<RequestBuilder, Box<dyn Error>> {
+        if let Some(auth_provider) = &self.auth_provider {
+            if let Some(ref client_id) = auth_provider.get_client_id() {
+                request = request.header("Client-Id", client_id);
             }
 
+            self.refresh(auth_provider.as_ref(), scopes).await?;
+
             let access_token = &*self.access_token.borrow();
 
             // FIXME: Check scope!
@@ -142,17 +318,142 @@ impl TwitchClientInner {
             request = request.header("Authorization", format!("Bearer {}", access_token.access_token));
         }
 
+        Ok(request)
+    }
+
+    /// The current access token, refreshed first if it expired.
+    #[cfg(any(feature = "tmi", feature = "pubsub"))]
+    pub(crate) async fn access_token(&self) -> Result<String, Box<dyn Error>> {
+        let auth_provider = self.auth_provider.as_ref()
+            .ok_or_else(|| String::from("Client has no auth provider!"))?;
+        self.refresh(auth_provider.as_ref(), None).await?;
+
+        Ok(self.access_token.borrow().access_token.clone())
+    }
+
+    async fn execute(request: RequestBuilder) -> Result<Response, Box<dyn Error>> {
+        //println!("{:?}", &request);
+
         let response = request.send().await?;
 
         //println!("{:?}", &response);
 
-        Ok(response.json()
+        if !response.status().is_success() {
+            let status = response.status();
+            let ratelimit_reset = response.headers()
+                .get("Ratelimit-Reset")
+                .and_then(|x| x.to_str().ok())
+                .and_then(|x| x.parse().ok());
+            let text = response.text().await?;
+
+            return Err(match serde_json::from_str::<ApiError>(&text) {
+                Ok(error) => ApiError {
+                    ratelimit_reset,
+                    ..error
+                }.into(),
+                Err(_) => format!("{}: {}", status, text).into()
+            });
+        }
+
+        Ok(response)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn send<S: Into<String>, Q: Serialize + ?Sized, B: Serialize + ?Sized>(&self, endpoint: ApiEndpoint, method: Method, scopes: Option<Vec<String>>, url: S, query: &Q, accept: &str, body: Option<&B>) -> Result<Response, Box<dyn Error>> {
+        let request = self.build(endpoint, method, url, query, accept, body)?;
+        let request = self.authorize(request, scopes).await?;
+        Self::execute(request).await
+    }
+
+    // Which of the REST helpers are used depends on the enabled endpoint groups.
+    #[allow(dead_code)]
+    pub(crate) async fn call<T: DeserializeOwned, S: Into<String>, Q: Serialize + ?Sized>(&self, endpoint: ApiEndpoint, method: Method, scopes: Option<Vec<String>>, url: S, query: &Q) -> Result<T, Box<dyn Error>> {
+        Ok(self.send(endpoint, method, scopes, url, query, "application/json", None::<&()>).await?
+            .json()
+            .await?)
+    }
+
+    #[allow(dead_code)]
+    pub(crate) async fn call_with_body<T: DeserializeOwned, S: Into<String>, Q: Serialize + ?Sized, B: Serialize + ?Sized>(&self, endpoint: ApiEndpoint, method: Method, scopes: Option<Vec<String>>, url: S, query: &Q, body: &B) -> Result<T, Box<dyn Error>> {
+        Ok(self.send(endpoint, method, scopes, url, query, "application/json", Some(body)).await?
+            .json()
+            .await?)
+    }
+
+    /// For endpoints that respond with something other than JSON, such as `text/calendar`.
+    #[allow(dead_code)]
+    pub(crate) async fn call_text<S: Into<String>, Q: Serialize + ?Sized>(&self, endpoint: ApiEndpoint, method: Method, scopes: Option<Vec<String>>, url: S, query: &Q, accept: &str) -> Result<String, Box<dyn Error>> {
+        Ok(self.send(endpoint, method, scopes, url, query, accept, None::<&()>).await?
+            .text()
             .await?)
     }
+
+    #[allow(dead_code)]
+    pub(crate) async fn call_empty<S: Into<String>, Q: Serialize + ?Sized>(&self, endpoint: ApiEndpoint, method: Method, scopes: Option<Vec<String>>, url: S, query: &Q) -> Result<(), Box<dyn Error>> {
+        self.send(endpoint, method, scopes, url, query, "application/json", None::<&()>).await?;
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub(crate) async fn call_empty_with_body<S: Into<String>, Q: Serialize + ?Sized, B: Serialize + ?Sized>(&self, endpoint: ApiEndpoint, method: Method, scopes: Option<Vec<String>>, url: S, query: &Q, body: &B) -> Result<(), Box<dyn Error>> {
+        self.send(endpoint, method, scopes, url, query, "application/json", Some(body)).await?;
+        Ok(())
+    }
+
+    /// Fetches a pre-signed URL returned by the API, such as an analytics report, without authorization.
+    #[cfg(feature = "analytics")]
+    pub(crate) async fn download<S: Into<String>>(&self, url: S) -> Result<Response, Box<dyn Error>> {
+        Self::execute(self.client.get(&url.into())).await
+    }
+
+    /// Fetches media from a CDN without authorization, starting at byte `offset` when it is not 0.
+    #[cfg(feature = "clips-download")]
+    pub(crate) async fn download_range<S: Into<String>>(&self, url: S, offset: u64) -> Result<Response, Box<dyn Error>> {
+        let mut request = self.client.get(&url.into());
+        if offset > 0 {
+            request = request.header("Range", format!("bytes={}-", offset));
+        }
+
+        Self::execute(request).await
+    }
+
+    #[cfg(feature = "clips-download")]
+    pub(crate) async fn head<S: Into<String>>(&self, url: S) -> Result<Response, Box<dyn Error>> {
+        Self::execute(self.client.head(&url.into())).await
+    }
+
+    /// Authenticates with a signed extension JWT instead of the client's access token.
+    #[cfg(feature = "extensions")]
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn call_with_jwt<T: DeserializeOwned, S: Into<String>, Q: Serialize + ?Sized, B: Serialize + ?Sized>(&self, endpoint: ApiEndpoint, method: Method, client_id: &str, jwt: &str, url: S, query: &Q, body: Option<&B>) -> Result<T, Box<dyn Error>> {
+        let request = self.build(endpoint, method, url, query, "application/json", body)?
+            .header("Client-Id", client_id)
+            .header("Authorization", format!("Bearer {}", jwt));
+
+        Ok(Self::execute(request).await?
+            .json()
+            .await?)
+    }
+
+    #[cfg(feature = "extensions")]
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn call_empty_with_jwt<S: Into<String>, Q: Serialize + ?Sized, B: Serialize + ?Sized>(&self, endpoint: ApiEndpoint, method: Method, client_id: &str, jwt: &str, url: S, query: &Q, body: Option<&B>) -> Result<(), Box<dyn Error>> {
+        let request = self.build(endpoint, method, url, query, "application/json", body)?
+            .header("Client-Id", client_id)
+            .header("Authorization", format!("Bearer {}", jwt));
+
+        Self::execute(request).await?;
+        Ok(())
+    }
 }
 
 impl TwitchClient {
     pub fn builder() -> TwitchClientBuilder {
         TwitchClientBuilder::new()
     }
+
+    #[cfg(any(feature = "tmi", feature = "pubsub"))]
+    pub(crate) async fn access_token(&self) -> Result<String, Box<dyn Error>> {
+        self._client.access_token().await
+    }
 }
@@ -0,0 +1,67 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::id::{BroadcasterId, GameId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::{BroadcasterCondition, ModeratorCondition};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelUpdateEvent {
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub title: String,
+    pub language: String,
+    pub category_id: GameId,
+    pub category_name: String,
+    #[serde(default)]
+    pub content_classification_labels: Vec<String>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelFollowEvent {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub followed_at: Timestamp,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+/// Exactly one of the two broadcasters should be set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RaidCondition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_broadcaster_user_id: Option<BroadcasterId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_broadcaster_user_id: Option<BroadcasterId>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelRaidEvent {
+    pub from_broadcaster_user_id: BroadcasterId,
+    pub from_broadcaster_user_login: String,
+    pub from_broadcaster_user_name: String,
+    pub to_broadcaster_user_id: BroadcasterId,
+    pub to_broadcaster_user_login: String,
+    pub to_broadcaster_user_name: String,
+    pub viewers: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+subscription_type!(ChannelUpdateV2, "channel.update", "2", BroadcasterCondition, ChannelUpdateEvent);
+subscription_type!(
+    /// Requires `moderator:read:followers` for the moderator.
+    ChannelFollowV2, "channel.follow", "2", ModeratorCondition, ChannelFollowEvent
+);
+subscription_type!(ChannelRaidV1, "channel.raid", "1", RaidCondition, ChannelRaidEvent);
@@ -0,0 +1,59 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::Timestamp;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RewardCondition {
+    pub broadcaster_user_id: BroadcasterId,
+    /// Only receive redemptions of this reward.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reward_id: Option<String>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum RedemptionEventStatus {
+    Unfulfilled,
+    Fulfilled,
+    Canceled,
+    /// Also used for any value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RedemptionReward {
+    pub id: String,
+    pub title: String,
+    pub cost: u64,
+    pub prompt: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelPointsRedemptionEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    #[serde(default)]
+    pub user_input: String,
+    pub status: RedemptionEventStatus,
+    pub reward: RedemptionReward,
+    pub redeemed_at: Timestamp,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+subscription_type!(ChannelPointsRedemptionAddV1, "channel.channel_points_custom_reward_redemption.add", "1", RewardCondition, ChannelPointsRedemptionEvent);
+subscription_type!(ChannelPointsRedemptionUpdateV1, "channel.channel_points_custom_reward_redemption.update", "1", RewardCondition, ChannelPointsRedemptionEvent);
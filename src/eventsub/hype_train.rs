@@ -0,0 +1,59 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::BroadcasterCondition;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum HypeTrainContributionType {
+    Bits,
+    Subscription,
+    Other,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HypeTrainContribution {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    #[serde(rename = "type")]
+    pub kind: HypeTrainContributionType,
+    pub total: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+/// Shared by the begin, progress and end events.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelHypeTrainEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub level: Option<u32>,
+    pub total: u64,
+    pub progress: Option<u64>,
+    pub goal: Option<u64>,
+    #[serde(default)]
+    pub top_contributions: Vec<HypeTrainContribution>,
+    pub last_contribution: Option<HypeTrainContribution>,
+    pub started_at: Timestamp,
+    pub expires_at: Option<Timestamp>,
+    pub ended_at: Option<Timestamp>,
+    pub cooldown_ends_at: Option<Timestamp>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+subscription_type!(ChannelHypeTrainBeginV1, "channel.hype_train.begin", "1", BroadcasterCondition, ChannelHypeTrainEvent);
+subscription_type!(ChannelHypeTrainProgressV1, "channel.hype_train.progress", "1", BroadcasterCondition, ChannelHypeTrainEvent);
+subscription_type!(ChannelHypeTrainEndV1, "channel.hype_train.end", "1", BroadcasterCondition, ChannelHypeTrainEvent);
@@ -0,0 +1,81 @@
+
+use std::fmt::Debug;
+use serde::{Serialize, Deserialize, de::DeserializeOwned};
+use crate::api::helix::id::{BroadcasterId, UserId};
+
+macro_rules! subscription_type {
+    ($(#[$meta:meta])* $name:ident, $kind:literal, $version:literal, $condition:ty, $event:ty) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+
+        impl crate::eventsub::EventSubscription for $name {
+            const TYPE: &'static str = $kind;
+            const VERSION: &'static str = $version;
+            type Condition = $condition;
+            type Event = $event;
+        }
+    };
+}
+
+mod stream;
+pub use stream::*;
+
+mod channel;
+pub use channel::*;
+
+mod subscriptions;
+pub use subscriptions::*;
+
+mod moderation;
+pub use moderation::*;
+
+mod channel_points;
+pub use channel_points::*;
+
+mod polls;
+pub use polls::*;
+
+mod predictions;
+pub use predictions::*;
+
+mod hype_train;
+pub use hype_train::*;
+
+mod user;
+pub use user::*;
+
+mod notification;
+pub use notification::*;
+
+mod webhook;
+pub use webhook::*;
+
+#[cfg(feature = "eventsub-websocket")]
+mod websocket;
+#[cfg(feature = "eventsub-websocket")]
+pub use websocket::*;
+
+/// An EventSub subscription type at a specific version, along with its condition and event payload.
+pub trait EventSubscription {
+    const TYPE: &'static str;
+    const VERSION: &'static str;
+    type Condition: Serialize + DeserializeOwned + Debug;
+    type Event: DeserializeOwned + Debug;
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BroadcasterCondition {
+    pub broadcaster_user_id: BroadcasterId
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModeratorCondition {
+    pub broadcaster_user_id: BroadcasterId,
+    pub moderator_user_id: UserId
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserCondition {
+    pub user_id: UserId
+}
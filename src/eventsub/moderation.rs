@@ -0,0 +1,63 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::BroadcasterCondition;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelBanEvent {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub moderator_user_id: UserId,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+    #[serde(default)]
+    pub reason: String,
+    pub banned_at: Timestamp,
+    /// Unset for permanent bans.
+    pub ends_at: Option<Timestamp>,
+    pub is_permanent: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelUnbanEvent {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub moderator_user_id: UserId,
+    pub moderator_user_login: String,
+    pub moderator_user_name: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelModeratorEvent {
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+subscription_type!(ChannelBanV1, "channel.ban", "1", BroadcasterCondition, ChannelBanEvent);
+subscription_type!(ChannelUnbanV1, "channel.unban", "1", BroadcasterCondition, ChannelUnbanEvent);
+subscription_type!(ChannelModeratorAddV1, "channel.moderator.add", "1", BroadcasterCondition, ChannelModeratorEvent);
+subscription_type!(ChannelModeratorRemoveV1, "channel.moderator.remove", "1", BroadcasterCondition, ChannelModeratorEvent);
@@ -0,0 +1,187 @@
+
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use crate::api::helix::eventsub::EventSubSubscription;
+use super::*;
+
+macro_rules! events {
+    ($($variant:ident($subscription:ty)),* $(,)?) => {
+        /// Any EventSub event, unknown types and versions are kept as raw JSON.
+        #[derive(Debug)]
+        pub enum EventSubEvent {
+            $($variant(<$subscription as EventSubscription>::Event),)*
+            Unknown(Value),
+            /// A known type and version whose payload did not match its model, so one odd event
+            /// does not fail the whole notification.
+            Malformed {
+                event: Value,
+                error: String
+            }
+        }
+
+        impl EventSubEvent {
+            /// Deserializes an event payload by its subscription type and version.
+            pub fn from_parts(kind: &str, version: &str, event: Value) -> Self {
+                $(
+                    if kind == <$subscription>::TYPE && version == <$subscription>::VERSION {
+                        return match serde_json::from_value(event.clone()) {
+                            Ok(parsed) => EventSubEvent::$variant(parsed),
+                            Err(error) => EventSubEvent::Malformed {
+                                event,
+                                error: error.to_string()
+                            }
+                        };
+                    }
+                )*
+
+                EventSubEvent::Unknown(event)
+            }
+        }
+    };
+}
+
+events! {
+    StreamOnline(StreamOnlineV1),
+    StreamOffline(StreamOfflineV1),
+    ChannelUpdate(ChannelUpdateV2),
+    ChannelFollow(ChannelFollowV2),
+    ChannelRaid(ChannelRaidV1),
+    ChannelSubscribe(ChannelSubscribeV1),
+    ChannelSubscriptionEnd(ChannelSubscriptionEndV1),
+    ChannelSubscriptionGift(ChannelSubscriptionGiftV1),
+    ChannelSubscriptionMessage(ChannelSubscriptionMessageV1),
+    ChannelCheer(ChannelCheerV1),
+    ChannelBan(ChannelBanV1),
+    ChannelUnban(ChannelUnbanV1),
+    ChannelModeratorAdd(ChannelModeratorAddV1),
+    ChannelModeratorRemove(ChannelModeratorRemoveV1),
+    ChannelPointsRedemptionAdd(ChannelPointsRedemptionAddV1),
+    ChannelPointsRedemptionUpdate(ChannelPointsRedemptionUpdateV1),
+    ChannelPollBegin(ChannelPollBeginV1),
+    ChannelPollProgress(ChannelPollProgressV1),
+    ChannelPollEnd(ChannelPollEndV1),
+    ChannelPredictionBegin(ChannelPredictionBeginV1),
+    ChannelPredictionProgress(ChannelPredictionProgressV1),
+    ChannelPredictionLock(ChannelPredictionLockV1),
+    ChannelPredictionEnd(ChannelPredictionEndV1),
+    ChannelHypeTrainBegin(ChannelHypeTrainBeginV1),
+    ChannelHypeTrainProgress(ChannelHypeTrainProgressV1),
+    ChannelHypeTrainEnd(ChannelHypeTrainEndV1),
+    UserUpdate(UserUpdateV1)
+}
+
+/// A notification payload, as delivered by both the webhook and WebSocket transports.
+#[derive(Debug)]
+pub struct EventSubNotification {
+    pub subscription: EventSubSubscription,
+    pub event: EventSubEvent
+}
+
+#[derive(Deserialize)]
+struct RawNotification {
+    subscription: EventSubSubscription,
+    event: Value
+}
+
+impl<'de> Deserialize<'de> for EventSubNotification {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawNotification::deserialize(deserializer)?;
+        let event = EventSubEvent::from_parts(&raw.subscription.kind, &raw.subscription.version, raw.event);
+
+        Ok(Self {
+            subscription: raw.subscription,
+            event
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    fn notification(kind: &str, event: Value) -> EventSubNotification {
+        serde_json::from_value(json!({
+            "subscription": {
+                "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+                "status": "enabled",
+                "type": kind,
+                "version": "1",
+                "condition": { "broadcaster_user_id": "1337" },
+                "transport": { "method": "webhook", "callback": "https://example.com/webhooks/callback" },
+                "created_at": "2019-11-16T10:11:12.634234626Z",
+                "cost": 0
+            },
+            "event": event
+        })).unwrap()
+    }
+
+    #[test]
+    fn typed_event() {
+        let notification = notification("stream.online", json!({
+            "id": "9001",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "type": "live",
+            "started_at": "2020-10-11T10:11:12.123Z",
+            "is_rerun": false
+        }));
+
+        match notification.event {
+            EventSubEvent::StreamOnline(event) => {
+                assert_eq!(event.broadcaster_user_id.as_str(), "1337");
+                assert_eq!(event.started_at.as_str(), "2020-10-11T10:11:12.123Z");
+                assert_eq!(event.extra.get("is_rerun"), Some(&json!(false)));
+            },
+            event => panic!("unexpected event {:?}", event)
+        }
+    }
+
+    #[test]
+    fn optional_fields() {
+        let notification = notification("channel.subscription.message", json!({
+            "user_id": "1234",
+            "user_login": "cool_user",
+            "user_name": "Cool_User",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cooler_user",
+            "broadcaster_user_name": "Cooler_User",
+            "tier": "1000",
+            "message": { "text": "Love the stream! FevziGG", "emotes": [{ "begin": 23, "end": 30, "id": "302976485" }] },
+            "cumulative_months": 15,
+            "streak_months": null
+        }));
+
+        match notification.event {
+            EventSubEvent::ChannelSubscriptionMessage(event) => {
+                assert_eq!(event.user_id.as_str(), "1234");
+                assert_eq!(event.streak_months, None);
+                assert_eq!(event.duration_months, None);
+                assert_eq!(event.message.emotes.map(|x| x.len()), Some(1));
+            },
+            event => panic!("unexpected event {:?}", event)
+        }
+    }
+
+    #[test]
+    fn malformed_event() {
+        let notification = notification("stream.online", json!({ "broadcaster_user_id": null }));
+
+        match notification.event {
+            EventSubEvent::Malformed { event, error } => {
+                assert_eq!(event, json!({ "broadcaster_user_id": null }));
+                assert!(!error.is_empty());
+            },
+            event => panic!("unexpected event {:?}", event)
+        }
+    }
+
+    #[test]
+    fn unknown_event() {
+        let notification = notification("channel.something_new", json!({ "foo": "bar" }));
+
+        assert!(matches!(notification.event, EventSubEvent::Unknown(_)));
+    }
+}
@@ -0,0 +1,65 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::Timestamp;
+use super::BroadcasterCondition;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum PollEventStatus {
+    Completed,
+    Archived,
+    Terminated,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+/// Vote counts are only set on progress and end events.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PollEventChoice {
+    pub id: String,
+    pub title: String,
+    pub bits_votes: Option<u64>,
+    pub channel_points_votes: Option<u64>,
+    pub votes: Option<u64>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PollVotingSetting {
+    pub is_enabled: bool,
+    pub amount_per_vote: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+/// Shared by the begin, progress and end events.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelPollEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub title: String,
+    pub choices: Vec<PollEventChoice>,
+    pub bits_voting: Option<PollVotingSetting>,
+    pub channel_points_voting: Option<PollVotingSetting>,
+    pub started_at: Timestamp,
+    pub ends_at: Option<Timestamp>,
+    pub status: Option<PollEventStatus>,
+    pub ended_at: Option<Timestamp>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+subscription_type!(ChannelPollBeginV1, "channel.poll.begin", "1", BroadcasterCondition, ChannelPollEvent);
+subscription_type!(ChannelPollProgressV1, "channel.poll.progress", "1", BroadcasterCondition, ChannelPollEvent);
+subscription_type!(ChannelPollEndV1, "channel.poll.end", "1", BroadcasterCondition, ChannelPollEvent);
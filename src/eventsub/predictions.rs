@@ -0,0 +1,79 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::Timestamp;
+use super::BroadcasterCondition;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum PredictionEventStatus {
+    Resolved,
+    Canceled,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum PredictionOutcomeColor {
+    Blue,
+    Pink,
+    /// A value Twitch added after this version of the crate.
+    #[serde(other)]
+    Unknown
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TopPredictor {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    pub channel_points_won: Option<u64>,
+    pub channel_points_used: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PredictionEventOutcome {
+    pub id: String,
+    pub title: String,
+    pub color: PredictionOutcomeColor,
+    pub users: Option<u64>,
+    pub channel_points: Option<u64>,
+    pub top_predictors: Option<Vec<TopPredictor>>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+/// Shared by the begin, progress, lock and end events.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelPredictionEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub title: String,
+    pub outcomes: Vec<PredictionEventOutcome>,
+    pub started_at: Timestamp,
+    pub locks_at: Option<Timestamp>,
+    pub locked_at: Option<Timestamp>,
+    pub winning_outcome_id: Option<String>,
+    pub status: Option<PredictionEventStatus>,
+    pub ended_at: Option<Timestamp>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+subscription_type!(ChannelPredictionBeginV1, "channel.prediction.begin", "1", BroadcasterCondition, ChannelPredictionEvent);
+subscription_type!(ChannelPredictionProgressV1, "channel.prediction.progress", "1", BroadcasterCondition, ChannelPredictionEvent);
+subscription_type!(ChannelPredictionLockV1, "channel.prediction.lock", "1", BroadcasterCondition, ChannelPredictionEvent);
+subscription_type!(ChannelPredictionEndV1, "channel.prediction.end", "1", BroadcasterCondition, ChannelPredictionEvent);
@@ -0,0 +1,34 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::id::BroadcasterId;
+use crate::api::helix::time::Timestamp;
+use super::BroadcasterCondition;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StreamOnlineEvent {
+    pub id: String,
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub started_at: Timestamp,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StreamOfflineEvent {
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+subscription_type!(StreamOnlineV1, "stream.online", "1", BroadcasterCondition, StreamOnlineEvent);
+subscription_type!(StreamOfflineV1, "stream.offline", "1", BroadcasterCondition, StreamOfflineEvent);
@@ -0,0 +1,100 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::id::{BroadcasterId, UserId};
+use super::BroadcasterCondition;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelSubscribeEvent {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub tier: String,
+    pub is_gift: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelSubscriptionGiftEvent {
+    /// Unset when the gift is anonymous.
+    pub user_id: Option<UserId>,
+    pub user_login: Option<String>,
+    pub user_name: Option<String>,
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub total: u64,
+    pub tier: String,
+    pub cumulative_total: Option<u64>,
+    pub is_anonymous: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MessageEmote {
+    pub begin: u32,
+    pub end: u32,
+    pub id: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubscriptionMessage {
+    pub text: String,
+    pub emotes: Option<Vec<MessageEmote>>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelSubscriptionMessageEvent {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub tier: String,
+    pub message: SubscriptionMessage,
+    pub cumulative_months: u32,
+    /// Unset when the user chose not to share their streak.
+    pub streak_months: Option<u32>,
+    pub duration_months: Option<u32>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelCheerEvent {
+    pub is_anonymous: bool,
+    /// Unset when the cheer is anonymous.
+    pub user_id: Option<UserId>,
+    pub user_login: Option<String>,
+    pub user_name: Option<String>,
+    pub broadcaster_user_id: BroadcasterId,
+    pub broadcaster_user_login: String,
+    pub broadcaster_user_name: String,
+    pub message: String,
+    pub bits: u64,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+subscription_type!(ChannelSubscribeV1, "channel.subscribe", "1", BroadcasterCondition, ChannelSubscribeEvent);
+subscription_type!(ChannelSubscriptionEndV1, "channel.subscription.end", "1", BroadcasterCondition, ChannelSubscribeEvent);
+subscription_type!(ChannelSubscriptionGiftV1, "channel.subscription.gift", "1", BroadcasterCondition, ChannelSubscriptionGiftEvent);
+subscription_type!(ChannelSubscriptionMessageV1, "channel.subscription.message", "1", BroadcasterCondition, ChannelSubscriptionMessageEvent);
+subscription_type!(ChannelCheerV1, "channel.cheer", "1", BroadcasterCondition, ChannelCheerEvent);
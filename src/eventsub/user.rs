@@ -0,0 +1,23 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::api::helix::id::UserId;
+use super::UserCondition;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserUpdateEvent {
+    pub user_id: UserId,
+    pub user_login: String,
+    pub user_name: String,
+    /// Only set with the `user:read:email` scope.
+    pub email: Option<String>,
+    #[serde(default)]
+    pub email_verified: bool,
+    pub description: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+subscription_type!(UserUpdateV1, "user.update", "1", UserCondition, UserUpdateEvent);
@@ -0,0 +1,399 @@
+
+use std::{collections::HashMap, error::Error, fmt, sync::Mutex, time::{Duration, SystemTime, UNIX_EPOCH}};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+
+use crate::api::helix::eventsub::EventSubSubscription;
+use super::EventSubNotification;
+
+pub const MESSAGE_ID_HEADER: &str = "Twitch-Eventsub-Message-Id";
+pub const MESSAGE_TIMESTAMP_HEADER: &str = "Twitch-Eventsub-Message-Timestamp";
+pub const MESSAGE_SIGNATURE_HEADER: &str = "Twitch-Eventsub-Message-Signature";
+pub const MESSAGE_TYPE_HEADER: &str = "Twitch-Eventsub-Message-Type";
+
+/// How far ahead of the local clock a message timestamp may be.
+const MAX_CLOCK_SKEW: Duration = Duration::from_secs(60);
+
+/// A webhook request that passed signature, age and replay checks.
+#[derive(Debug)]
+pub enum WebhookMessage {
+    Notification(Box<EventSubNotification>),
+    /// Must be answered with the challenge as a plain text 200 response.
+    Challenge {
+        challenge: String,
+        subscription: EventSubSubscription
+    },
+    Revocation(EventSubSubscription)
+}
+
+#[derive(Debug)]
+pub enum WebhookRejection {
+    MissingHeader(&'static str),
+    InvalidSignature,
+    InvalidTimestamp,
+    /// Older than the verifier's maximum age.
+    Expired,
+    /// Dated further ahead than clocks plausibly drift apart.
+    FromFuture,
+    /// A message ID that was already accepted, Twitch retries deliveries it thinks failed.
+    Replayed,
+    InvalidBody(serde_json::Error),
+    UnknownMessageType(String)
+}
+
+impl WebhookRejection {
+    /// The status code to answer Twitch with, duplicates are acknowledged so they are not retried.
+    pub fn status(&self) -> u16 {
+        match self {
+            WebhookRejection::Replayed => 204,
+            WebhookRejection::InvalidBody(_) | WebhookRejection::UnknownMessageType(_) => 400,
+            _ => 403
+        }
+    }
+}
+
+impl fmt::Display for WebhookRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookRejection::MissingHeader(header) => write!(f, "Missing {} header!", header),
+            WebhookRejection::InvalidSignature => write!(f, "Invalid message signature!"),
+            WebhookRejection::InvalidTimestamp => write!(f, "Invalid message timestamp!"),
+            WebhookRejection::Expired => write!(f, "Message is too old!"),
+            WebhookRejection::FromFuture => write!(f, "Message is dated in the future!"),
+            WebhookRejection::Replayed => write!(f, "Message was already received!"),
+            WebhookRejection::InvalidBody(error) => write!(f, "Invalid message body: {}", error),
+            WebhookRejection::UnknownMessageType(kind) => write!(f, "Unknown message type {}!", kind)
+        }
+    }
+}
+
+impl Error for WebhookRejection {}
+
+#[derive(Deserialize)]
+struct ChallengeBody {
+    challenge: String,
+    subscription: EventSubSubscription
+}
+
+#[derive(Deserialize)]
+struct RevocationBody {
+    subscription: EventSubSubscription
+}
+
+/// Verifies EventSub webhook requests independently of the HTTP server in use.
+pub struct WebhookVerifier {
+    secret: Vec<u8>,
+    max_age: Duration,
+    seen: Mutex<HashMap<String, SystemTime>>
+}
+
+impl WebhookVerifier {
+    /// `secret` is the one given in the subscription's webhook transport.
+    pub fn new<S: Into<String>>(secret: S) -> Self {
+        Self {
+            secret: secret.into().into_bytes(),
+            max_age: Duration::from_secs(600),
+            seen: Mutex::new(HashMap::new())
+        }
+    }
+
+    /// Defaults to 10 minutes, as recommended by Twitch.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// `header` looks up a request header by name, it should ignore case.
+    pub fn verify<F: Fn(&str) -> Option<String>>(&self, header: F, body: &[u8]) -> Result<WebhookMessage, WebhookRejection> {
+        let required = |name: &'static str| header(name).ok_or(WebhookRejection::MissingHeader(name));
+        let id = required(MESSAGE_ID_HEADER)?;
+        let timestamp = required(MESSAGE_TIMESTAMP_HEADER)?;
+        let signature = required(MESSAGE_SIGNATURE_HEADER)?;
+        let kind = required(MESSAGE_TYPE_HEADER)?;
+
+        let signature = signature.strip_prefix("sha256=")
+            .and_then(decode_hex)
+            .ok_or(WebhookRejection::InvalidSignature)?;
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret)
+            .map_err(|_| WebhookRejection::InvalidSignature)?;
+        mac.update(id.as_bytes());
+        mac.update(timestamp.as_bytes());
+        mac.update(body);
+        mac.verify_slice(&signature).map_err(|_| WebhookRejection::InvalidSignature)?;
+
+        let sent_at = parse_timestamp(&timestamp).ok_or(WebhookRejection::InvalidTimestamp)?;
+        let now = SystemTime::now();
+        if now.duration_since(sent_at).is_ok_and(|x| x > self.max_age) {
+            return Err(WebhookRejection::Expired);
+        }
+
+        if sent_at.duration_since(now).is_ok_and(|x| x > MAX_CLOCK_SKEW) {
+            return Err(WebhookRejection::FromFuture);
+        }
+
+        if self.seen.lock().unwrap().contains_key(&id) {
+            return Err(WebhookRejection::Replayed);
+        }
+
+        let message = match kind.as_str() {
+            "notification" => WebhookMessage::Notification(Box::new(serde_json::from_slice(body).map_err(WebhookRejection::InvalidBody)?)),
+            "webhook_callback_verification" => {
+                let body: ChallengeBody = serde_json::from_slice(body).map_err(WebhookRejection::InvalidBody)?;
+                WebhookMessage::Challenge {
+                    challenge: body.challenge,
+                    subscription: body.subscription
+                }
+            },
+            "revocation" => {
+                let body: RevocationBody = serde_json::from_slice(body).map_err(WebhookRejection::InvalidBody)?;
+                WebhookMessage::Revocation(body.subscription)
+            },
+            _ => return Err(WebhookRejection::UnknownMessageType(kind))
+        };
+
+        let mut seen = self.seen.lock().unwrap();
+        seen.retain(|_, x| now.duration_since(*x).map_or(true, |x| x <= self.max_age));
+        if seen.insert(id, sent_at).is_some() {
+            return Err(WebhookRejection::Replayed);
+        }
+
+        Ok(message)
+    }
+
+    /// Verifies a request and builds the response to send back to Twitch, for `http` based servers like hyper and warp.
+    #[cfg(feature = "eventsub-http")]
+    pub fn handle_http(&self, headers: &http::HeaderMap, body: &[u8]) -> (http::Response<String>, Result<WebhookMessage, WebhookRejection>) {
+        let result = self.verify(|name| headers.get(name).and_then(|x| x.to_str().ok()).map(String::from), body);
+
+        let response = match &result {
+            Ok(WebhookMessage::Challenge { challenge, .. }) => http::Response::builder()
+                .status(200)
+                .header("Content-Type", "text/plain")
+                .body(challenge.clone()),
+            Ok(_) => http::Response::builder()
+                .status(204)
+                .body(String::new()),
+            Err(rejection) => http::Response::builder()
+                .status(rejection.status())
+                .body(if rejection.status() == 204 { String::new() } else { rejection.to_string() })
+        };
+
+        (response.expect("webhook response should be valid"), result)
+    }
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    value.as_bytes()
+        .chunks(2)
+        .map(|x| match x {
+            [high, low] => Some(((*high as char).to_digit(16)? * 16 + (*low as char).to_digit(16)?) as u8),
+            _ => None
+        })
+        .collect()
+}
+
+/// Parses the RFC3339 timestamps sent by Twitch, such as `2023-07-19T10:11:12.123456789Z`.
+fn parse_timestamp(value: &str) -> Option<SystemTime> {
+    let number = |range: std::ops::Range<usize>| value.get(range).and_then(|x| x.parse::<i64>().ok());
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+
+    let mut rest = value.get(19..)?;
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.find(|x: char| !x.is_ascii_digit()).unwrap_or(fraction.len());
+        let padded = format!("{:0<9}", &fraction[..digits.min(9)]);
+        nanos = padded.parse::<u32>().ok()?;
+        rest = &fraction[digits..];
+    }
+
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match rest.get(0..1)? { "+" => 1, "-" => -1, _ => return None };
+            let hours = rest.get(1..3)?.parse::<i64>().ok()?;
+            let minutes = rest.get(4..6)?.parse::<i64>().ok()?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+    };
+
+    // Days since the epoch from a civil date, after Howard Hinnant's algorithm.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    let seconds = days * 86400 + hour * 3600 + minute * 60 + second - offset;
+    if seconds < 0 {
+        return None;
+    }
+
+    Some(UNIX_EPOCH + Duration::new(seconds as u64, nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, time::{Duration, UNIX_EPOCH}};
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+    use super::*;
+
+    const SECRET: &str = "s3cRe7";
+    const ID: &str = "e76c6bd4-55c9-4987-8304-da1588d8988b";
+    const TIMESTAMP: &str = "2019-11-16T10:11:12.634234626Z";
+    const CHALLENGE: &str = r#"{"challenge":"pogchamp-kappa-360noscope-vohiyo","subscription":{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","status":"webhook_callback_verification_pending","type":"channel.follow","version":"2","cost":1,"condition":{"broadcaster_user_id":"12826"},"transport":{"method":"webhook","callback":"https://example.com/webhooks/callback"},"created_at":"2019-11-16T10:11:12.634234626Z"}}"#;
+    /// HMAC-SHA256 of `ID`, `TIMESTAMP` and `CHALLENGE` with `SECRET`, computed independently.
+    const CHALLENGE_SIGNATURE: &str = "sha256=e356332a8f740309286f50a95408f5b177ce4aa0ac7470aaa337da6ccd090c26";
+
+    const REVOCATION: &str = r#"{"subscription":{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","status":"authorization_revoked","type":"channel.follow","version":"2","cost":1,"condition":{"broadcaster_user_id":"12826"},"transport":{"method":"webhook","callback":"https://example.com/webhooks/callback"},"created_at":"2019-11-16T10:11:12.634234626Z"}}"#;
+
+    /// Accepts the 2019 fixtures, the age check is tested separately.
+    fn verifier() -> WebhookVerifier {
+        WebhookVerifier::new(SECRET).with_max_age(Duration::from_secs(u64::MAX))
+    }
+
+    fn sign(id: &str, timestamp: &str, body: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+        mac.update(id.as_bytes());
+        mac.update(timestamp.as_bytes());
+        mac.update(body.as_bytes());
+        let signature: String = mac.finalize().into_bytes().iter().map(|x| format!("{:02x}", x)).collect();
+        format!("sha256={}", signature)
+    }
+
+    fn verify(verifier: &WebhookVerifier, headers: &[(&str, &str)], body: &str) -> Result<WebhookMessage, WebhookRejection> {
+        let headers: HashMap<String, String> = headers.iter().map(|(k, v)| (k.to_lowercase(), v.to_string())).collect();
+        verifier.verify(|name| headers.get(&name.to_lowercase()).cloned(), body.as_bytes())
+    }
+
+    fn headers<'a>(id: &'a str, timestamp: &'a str, signature: &'a str, kind: &'a str) -> Vec<(&'a str, &'a str)> {
+        vec![
+            (MESSAGE_ID_HEADER, id),
+            (MESSAGE_TIMESTAMP_HEADER, timestamp),
+            (MESSAGE_SIGNATURE_HEADER, signature),
+            (MESSAGE_TYPE_HEADER, kind)
+        ]
+    }
+
+    #[test]
+    fn known_signature() {
+        match verify(&verifier(), &headers(ID, TIMESTAMP, CHALLENGE_SIGNATURE, "webhook_callback_verification"), CHALLENGE) {
+            Ok(WebhookMessage::Challenge { challenge, subscription }) => {
+                assert_eq!(challenge, "pogchamp-kappa-360noscope-vohiyo");
+                assert_eq!(subscription.kind, "channel.follow");
+            },
+            other => panic!("unexpected result {:?}", other)
+        }
+    }
+
+    #[test]
+    fn tampered_body() {
+        let body = CHALLENGE.replace("12826", "12827");
+        let result = verify(&verifier(), &headers(ID, TIMESTAMP, CHALLENGE_SIGNATURE, "webhook_callback_verification"), &body);
+        assert!(matches!(result, Err(WebhookRejection::InvalidSignature)));
+    }
+
+    #[test]
+    fn bad_signature_format() {
+        let verifier = verifier();
+        let hex = CHALLENGE_SIGNATURE.trim_start_matches("sha256=");
+        let sha1 = format!("sha1={}", hex);
+        let odd = format!("sha256={}0", hex);
+        let not_hex = format!("sha256=zz{}", &hex[2..]);
+
+        for signature in [hex, sha1.as_str(), odd.as_str(), not_hex.as_str(), ""] {
+            let result = verify(&verifier, &headers(ID, TIMESTAMP, signature, "webhook_callback_verification"), CHALLENGE);
+            assert!(matches!(result, Err(WebhookRejection::InvalidSignature)), "{}", signature);
+        }
+    }
+
+    #[test]
+    fn missing_header() {
+        let mut headers = headers(ID, TIMESTAMP, CHALLENGE_SIGNATURE, "webhook_callback_verification");
+        headers.remove(0);
+        let result = verify(&verifier(), &headers, CHALLENGE);
+        assert!(matches!(result, Err(WebhookRejection::MissingHeader(MESSAGE_ID_HEADER))));
+    }
+
+    #[test]
+    fn replayed() {
+        let verifier = verifier();
+        let headers = headers(ID, TIMESTAMP, CHALLENGE_SIGNATURE, "webhook_callback_verification");
+
+        assert!(verify(&verifier, &headers, CHALLENGE).is_ok());
+        let result = verify(&verifier, &headers, CHALLENGE);
+        assert!(matches!(result, Err(WebhookRejection::Replayed)));
+        assert_eq!(result.unwrap_err().status(), 204);
+    }
+
+    #[test]
+    fn expired() {
+        let verifier = WebhookVerifier::new(SECRET);
+        let result = verify(&verifier, &headers(ID, TIMESTAMP, CHALLENGE_SIGNATURE, "webhook_callback_verification"), CHALLENGE);
+        assert!(matches!(result, Err(WebhookRejection::Expired)));
+    }
+
+    #[test]
+    fn from_future() {
+        let timestamp = "2999-01-01T00:00:00Z";
+        let signature = sign(ID, timestamp, CHALLENGE);
+        let result = verify(&verifier(), &headers(ID, timestamp, &signature, "webhook_callback_verification"), CHALLENGE);
+        assert!(matches!(result, Err(WebhookRejection::FromFuture)));
+    }
+
+    #[test]
+    fn invalid_timestamp() {
+        let timestamp = "yesterday";
+        let signature = sign(ID, timestamp, CHALLENGE);
+        let result = verify(&verifier(), &headers(ID, timestamp, &signature, "webhook_callback_verification"), CHALLENGE);
+        assert!(matches!(result, Err(WebhookRejection::InvalidTimestamp)));
+    }
+
+    #[test]
+    fn revocation() {
+        let signature = sign(ID, TIMESTAMP, REVOCATION);
+        match verify(&verifier(), &headers(ID, TIMESTAMP, &signature, "revocation"), REVOCATION) {
+            Ok(WebhookMessage::Revocation(subscription)) => assert_eq!(subscription.id, "f1c2a387-161a-49f9-a165-0f21d7a4e1c4"),
+            other => panic!("unexpected result {:?}", other)
+        }
+    }
+
+    #[test]
+    fn unknown_message_type() {
+        let signature = sign(ID, TIMESTAMP, REVOCATION);
+        let result = verify(&verifier(), &headers(ID, TIMESTAMP, &signature, "something_else"), REVOCATION);
+        assert!(matches!(result, Err(WebhookRejection::UnknownMessageType(_))));
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(decode_hex("00ff10Ab"), Some(vec![0x00, 0xff, 0x10, 0xab]));
+        assert_eq!(decode_hex(""), Some(vec![]));
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("zz"), None);
+        assert_eq!(decode_hex("+1"), None);
+    }
+
+    #[test]
+    fn timestamps() {
+        let at = |seconds: u64, nanos: u32| Some(UNIX_EPOCH + Duration::new(seconds, nanos));
+
+        assert_eq!(parse_timestamp("1970-01-01T00:00:00Z"), at(0, 0));
+        assert_eq!(parse_timestamp("2019-11-16T10:11:12Z"), at(1573899072, 0));
+        assert_eq!(parse_timestamp("2019-11-16T10:11:12.634234626Z"), at(1573899072, 634234626));
+        assert_eq!(parse_timestamp("2019-11-16T10:11:12.5Z"), at(1573899072, 500000000));
+        assert_eq!(parse_timestamp("2019-11-16T10:11:12.1234567891234Z"), at(1573899072, 123456789));
+        assert_eq!(parse_timestamp("2019-11-16T12:41:12+02:30"), at(1573899072, 0));
+        assert_eq!(parse_timestamp("2019-11-16T05:11:12.25-05:00"), at(1573899072, 250000000));
+        assert_eq!(parse_timestamp("2020-02-29T00:00:00Z"), at(1582934400, 0));
+
+        assert_eq!(parse_timestamp("2019-11-16T10:11:12"), None);
+        assert_eq!(parse_timestamp("2019-11-16 10:11"), None);
+        assert_eq!(parse_timestamp("2019-11-16T10:11:12*01:00"), None);
+        assert_eq!(parse_timestamp("1969-12-31T23:59:59Z"), None);
+        assert_eq!(parse_timestamp(""), None);
+    }
+}
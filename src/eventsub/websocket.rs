@@ -0,0 +1,432 @@
+
+use std::{collections::VecDeque, error::Error, time::Duration};
+use async_tungstenite::{tokio::{connect_async, ConnectStream}, tungstenite::{Message, protocol::frame::coding::CloseCode}, WebSocketStream};
+use futures::{Stream, StreamExt, stream};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{TwitchClient, api::helix::{result::ApiResult, time::Timestamp, eventsub::{CreateEventSubSubscription, CreateEventSubSubscriptionRequest, EventSubSubscription, EventSubTotals, EventSubTransport}}};
+use super::{EventSubNotification, EventSubscription};
+
+pub const EVENTSUB_WEBSOCKET_URL: &str = "wss://eventsub.wss.twitch.tv/ws";
+
+/// Twitch closes sessions without a subscription after 10 seconds, the welcome must arrive before that.
+const WELCOME_TIMEOUT: Duration = Duration::from_secs(10);
+/// Extra time on top of the keepalive timeout before the connection is considered dead.
+const KEEPALIVE_GRACE: Duration = Duration::from_secs(5);
+/// How long the old connection is read after a reconnect, to collect events sent before the switch.
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(1);
+/// Number of message IDs remembered to drop redelivered events.
+const SEEN_MESSAGES: usize = 1000;
+
+type Socket = WebSocketStream<ConnectStream>;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebSocketSession {
+    pub id: String,
+    pub status: String,
+    pub connected_at: Timestamp,
+    pub keepalive_timeout_seconds: Option<u64>,
+    pub reconnect_url: Option<String>
+}
+
+#[derive(Debug)]
+pub enum WebSocketMessage {
+    Notification(Box<EventSubNotification>),
+    Revocation(Box<EventSubSubscription>)
+}
+
+#[derive(Deserialize)]
+struct FrameMetadata {
+    message_id: String,
+    message_type: String
+}
+
+#[derive(Deserialize)]
+struct Frame {
+    metadata: FrameMetadata,
+    payload: Value
+}
+
+#[derive(Deserialize)]
+struct SessionPayload {
+    session: WebSocketSession
+}
+
+#[derive(Deserialize)]
+struct RevocationPayload {
+    subscription: EventSubSubscription
+}
+
+/// EventSub over WebSocket, for applications without a public HTTPS endpoint.
+///
+/// Subscriptions must be created for `session().id` with a user access token, `subscribe` does this through the Helix client.
+pub struct EventSubWebSocket {
+    socket: Socket,
+    session: WebSocketSession,
+    pending: VecDeque<WebSocketMessage>,
+    seen: VecDeque<String>,
+    closed: bool
+}
+
+impl EventSubWebSocket {
+    pub async fn connect() -> Result<Self, Box<dyn Error>> {
+        Self::connect_to(EVENTSUB_WEBSOCKET_URL).await
+    }
+
+    /// Connects to another server, such as the Twitch CLI's mock server.
+    pub async fn connect_to<S: Into<String>>(url: S) -> Result<Self, Box<dyn Error>> {
+        let (socket, session) = open(url.into()).await?;
+
+        Ok(Self {
+            socket,
+            session,
+            pending: VecDeque::new(),
+            seen: VecDeque::new(),
+            closed: false
+        })
+    }
+
+    pub fn session(&self) -> &WebSocketSession {
+        &self.session
+    }
+
+    pub fn transport(&self) -> EventSubTransport {
+        EventSubTransport::Websocket {
+            session_id: self.session.id.clone()
+        }
+    }
+
+    /// Creates a subscription delivered to this session.
+    pub async fn subscribe<T: EventSubscription>(&self, client: &TwitchClient, condition: T::Condition) -> Result<ApiResult<EventSubSubscription, EventSubTotals>, Box<dyn Error>> {
+        let request = CreateEventSubSubscriptionRequest::default()
+            .with_subscription::<T>(condition)
+            .with_transport(self.transport());
+
+        client.eventsub.create_eventsub_subscription(request).await
+    }
+
+    /// Waits for the next message, returns `None` once Twitch closes the session.
+    ///
+    /// A frame that fails to decode is returned as an error, the session stays open.
+    pub async fn next_message(&mut self) -> Option<Result<WebSocketMessage, Box<dyn Error>>> {
+        loop {
+            if let Some(message) = self.pending.pop_front() {
+                return Some(Ok(message));
+            }
+
+            if self.closed {
+                return None;
+            }
+
+            let timeout = self.session.keepalive_timeout_seconds.map(|x| Duration::from_secs(x) + KEEPALIVE_GRACE);
+            let text = match read_text(&mut self.socket, timeout).await {
+                Ok(Some(text)) => text,
+                Ok(None) => {
+                    self.closed = true;
+                    continue;
+                },
+                Err(error) => {
+                    self.closed = true;
+                    return Some(Err(error));
+                }
+            };
+
+            match self.handle_text(&text).await {
+                Ok(Some(message)) => return Some(Ok(message)),
+                Ok(None) => {},
+                Err(error) => return Some(Err(error))
+            }
+        }
+    }
+
+    pub fn into_stream(self) -> impl Stream<Item = Result<WebSocketMessage, Box<dyn Error>>> {
+        stream::unfold(self, |mut socket| async move {
+            socket.next_message().await.map(|x| (x, socket))
+        })
+    }
+
+    async fn handle_text(&mut self, text: &str) -> Result<Option<WebSocketMessage>, Box<dyn Error>> {
+        let frame = parse_frame(text)?;
+
+        if frame.metadata.message_type == "session_reconnect" {
+            let payload: SessionPayload = serde_json::from_value(frame.payload)?;
+            let url = payload.session.reconnect_url
+                .ok_or_else(|| String::from("EventSub reconnect message has no URL!"))?;
+
+            if let Err(error) = self.reconnect(url).await {
+                self.closed = true;
+                return Err(error);
+            }
+
+            return Ok(None);
+        }
+
+        self.handle_frame(frame)
+    }
+
+    /// Switches to the new connection once it is welcomed, then collects what the old one still had buffered.
+    async fn reconnect(&mut self, url: String) -> Result<(), Box<dyn Error>> {
+        let (mut old, session) = open(url).await?;
+        std::mem::swap(&mut self.socket, &mut old);
+        self.session = session;
+
+        while let Ok(Some(text)) = read_text(&mut old, Some(DRAIN_TIMEOUT)).await {
+            // A bad frame only loses itself, the rest of the buffer is still delivered.
+            if let Ok(Some(message)) = parse_frame(&text).and_then(|frame| self.handle_frame(frame)) {
+                self.pending.push_back(message);
+            }
+        }
+
+        let _ = old.close(None).await;
+        Ok(())
+    }
+
+    fn handle_frame(&mut self, frame: Frame) -> Result<Option<WebSocketMessage>, Box<dyn Error>> {
+        let message = match frame.metadata.message_type.as_str() {
+            "notification" => WebSocketMessage::Notification(Box::new(serde_json::from_value(frame.payload)?)),
+            "revocation" => {
+                let payload: RevocationPayload = serde_json::from_value(frame.payload)?;
+                WebSocketMessage::Revocation(Box::new(payload.subscription))
+            },
+            _ => return Ok(None)
+        };
+
+        if self.seen.contains(&frame.metadata.message_id) {
+            return Ok(None);
+        }
+
+        if self.seen.len() >= SEEN_MESSAGES {
+            self.seen.pop_front();
+        }
+        self.seen.push_back(frame.metadata.message_id);
+
+        Ok(Some(message))
+    }
+}
+
+async fn open(url: String) -> Result<(Socket, WebSocketSession), Box<dyn Error>> {
+    let (mut socket, _) = connect_async(url).await?;
+
+    loop {
+        let frame = match read_text(&mut socket, Some(WELCOME_TIMEOUT)).await? {
+            Some(text) => parse_frame(&text)?,
+            None => return Err(String::from("EventSub WebSocket closed before the welcome message!").into())
+        };
+
+        if frame.metadata.message_type == "session_welcome" {
+            let payload: SessionPayload = serde_json::from_value(frame.payload)?;
+            return Ok((socket, payload.session));
+        }
+    }
+}
+
+fn parse_frame(text: &str) -> Result<Frame, Box<dyn Error>> {
+    Ok(serde_json::from_str(text)?)
+}
+
+/// Only fails on transport errors, decoding is left to the caller.
+async fn read_text(socket: &mut Socket, timeout: Option<Duration>) -> Result<Option<String>, Box<dyn Error>> {
+    loop {
+        let message = match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, socket.next()).await
+                .map_err(|_| String::from("EventSub WebSocket timed out!"))?,
+            None => socket.next().await
+        };
+
+        match message.transpose()? {
+            Some(Message::Text(text)) => return Ok(Some(text)),
+            Some(Message::Close(Some(frame))) if frame.code != CloseCode::Normal => {
+                return Err(format!("EventSub WebSocket closed with {}: {}", u16::from(frame.code), frame.reason).into());
+            },
+            Some(Message::Close(_)) | None => return Ok(None),
+            Some(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use async_tungstenite::tokio::{accept_async, TokioAdapter};
+    use futures::SinkExt;
+    use serde_json::json;
+    use tokio::net::{TcpListener, TcpStream};
+    use crate::api::helix::eventsub::EventSubStatus;
+    use super::*;
+
+    type Server = WebSocketStream<TokioAdapter<TcpStream>>;
+
+    /// Accepts a single connection and hands it to `handler`, returns the URL to connect to.
+    async fn serve<F, Fut>(handler: F) -> String
+    where
+        F: FnOnce(Server) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send
+    {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            handler(accept_async(stream).await.unwrap()).await;
+        });
+
+        url
+    }
+
+    /// Closes normally and waits for the client to let go.
+    async fn close(mut server: Server) {
+        let _ = server.close(None).await;
+        while let Some(Ok(_)) = server.next().await {}
+    }
+
+    fn frame(id: &str, message_type: &str, payload: Value) -> Message {
+        Message::Text(json!({
+            "metadata": {
+                "message_id": id,
+                "message_type": message_type,
+                "message_timestamp": "2023-07-19T14:56:51.634234626Z"
+            },
+            "payload": payload
+        }).to_string())
+    }
+
+    fn session(id: &str, status: &str, reconnect_url: Option<&str>) -> Value {
+        json!({
+            "session": {
+                "id": id,
+                "status": status,
+                "connected_at": "2023-07-19T14:56:51.616329898Z",
+                "keepalive_timeout_seconds": if reconnect_url.is_some() { Value::Null } else { json!(10) },
+                "reconnect_url": reconnect_url
+            }
+        })
+    }
+
+    fn subscription(id: &str, status: &str) -> Value {
+        json!({
+            "id": id,
+            "status": status,
+            "type": "stream.online",
+            "version": "1",
+            "condition": { "broadcaster_user_id": "1337" },
+            "transport": { "method": "websocket", "session_id": "AQoQexAWVYKSTIu4ec_2VAxyuhAB" },
+            "created_at": "2023-07-19T14:56:51.634234626Z",
+            "cost": 0
+        })
+    }
+
+    /// The subscription ID doubles as a marker for which notification arrived.
+    fn notification(id: &str) -> Message {
+        frame(id, "notification", json!({
+            "subscription": subscription(id, "enabled"),
+            "event": {
+                "id": "9001",
+                "broadcaster_user_id": "1337",
+                "broadcaster_user_login": "cool_user",
+                "broadcaster_user_name": "Cool_User",
+                "type": "live",
+                "started_at": "2023-07-19T14:56:51.634234626Z"
+            }
+        }))
+    }
+
+    async fn next_notification(socket: &mut EventSubWebSocket) -> String {
+        match socket.next_message().await {
+            Some(Ok(WebSocketMessage::Notification(notification))) => notification.subscription.id,
+            message => panic!("unexpected message {:?}", message)
+        }
+    }
+
+    #[tokio::test]
+    async fn welcome() {
+        let url = serve(|mut server| async move {
+            server.send(frame("1", "session_welcome", session("AQoQexAWVYKSTIu4ec_2VAxyuhAB", "connected", None))).await.unwrap();
+            close(server).await;
+        }).await;
+
+        let mut socket = EventSubWebSocket::connect_to(url).await.unwrap();
+        assert_eq!(socket.session().id, "AQoQexAWVYKSTIu4ec_2VAxyuhAB");
+        assert_eq!(socket.session().keepalive_timeout_seconds, Some(10));
+        assert!(socket.next_message().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn keepalive_timeout() {
+        let url = serve(|mut server| async move {
+            server.send(frame("1", "session_welcome", session("a", "connected", None))).await.unwrap();
+            while let Some(Ok(_)) = server.next().await {}
+        }).await;
+
+        let mut socket = EventSubWebSocket::connect_to(url).await.unwrap();
+        tokio::time::pause();
+        let start = tokio::time::Instant::now();
+
+        assert!(socket.next_message().await.unwrap().is_err());
+        assert!(start.elapsed() >= Duration::from_secs(10) + KEEPALIVE_GRACE);
+        assert!(socket.next_message().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn bad_frame_keeps_session() {
+        let url = serve(|mut server| async move {
+            server.send(frame("1", "session_welcome", session("a", "connected", None))).await.unwrap();
+            server.send(Message::Text(String::from("{\"metadata\":"))).await.unwrap();
+            server.send(notification("2")).await.unwrap();
+            close(server).await;
+        }).await;
+
+        let mut socket = EventSubWebSocket::connect_to(url).await.unwrap();
+        assert!(socket.next_message().await.unwrap().is_err());
+        assert_eq!(next_notification(&mut socket).await, "2");
+        assert!(socket.next_message().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn reconnect_keeps_buffered_events() {
+        let new = serve(|mut server| async move {
+            server.send(frame("5", "session_welcome", session("b", "connected", None))).await.unwrap();
+            // Twitch may deliver an event on both connections around the switch.
+            server.send(notification("4")).await.unwrap();
+            server.send(notification("6")).await.unwrap();
+            close(server).await;
+        }).await;
+
+        let old = serve(move |mut server| async move {
+            server.send(frame("1", "session_welcome", session("a", "connected", None))).await.unwrap();
+            server.send(notification("2")).await.unwrap();
+            server.send(frame("3", "session_reconnect", session("a", "reconnecting", Some(&new)))).await.unwrap();
+            server.send(notification("4")).await.unwrap();
+            server.send(Message::Text(String::from("not a frame"))).await.unwrap();
+            server.send(notification("7")).await.unwrap();
+            close(server).await;
+        }).await;
+
+        let mut socket = EventSubWebSocket::connect_to(old).await.unwrap();
+        assert_eq!(next_notification(&mut socket).await, "2");
+        assert_eq!(next_notification(&mut socket).await, "4");
+        assert_eq!(socket.session().id, "b");
+        assert_eq!(next_notification(&mut socket).await, "7");
+        assert_eq!(next_notification(&mut socket).await, "6");
+        assert!(socket.next_message().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn revocation() {
+        let url = serve(|mut server| async move {
+            server.send(frame("1", "session_welcome", session("a", "connected", None))).await.unwrap();
+            server.send(frame("2", "revocation", json!({ "subscription": subscription("f1c2a387-161a-49f9-a165-0f21d7a4e1c4", "authorization_revoked") }))).await.unwrap();
+            close(server).await;
+        }).await;
+
+        let mut socket = EventSubWebSocket::connect_to(url).await.unwrap();
+        match socket.next_message().await {
+            Some(Ok(WebSocketMessage::Revocation(subscription))) => {
+                assert_eq!(subscription.id, "f1c2a387-161a-49f9-a165-0f21d7a4e1c4");
+                assert_eq!(subscription.status, EventSubStatus::AuthorizationRevoked);
+            },
+            message => panic!("unexpected message {:?}", message)
+        }
+        assert!(socket.next_message().await.is_none());
+    }
+}
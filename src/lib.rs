@@ -3,4 +3,10 @@ mod auth;
 mod client;
 mod util;
 pub mod api;
+#[cfg(feature = "tmi")]
+pub mod chat;
+#[cfg(feature = "pubsub")]
+pub mod pubsub;
+#[cfg(feature = "eventsub")]
+pub mod eventsub;
 pub use client::*;
\ No newline at end of file
@@ -0,0 +1,344 @@
+
+use std::{collections::HashSet, error::Error, sync::{Arc, Mutex}, time::{Duration, SystemTime, UNIX_EPOCH}};
+use async_tungstenite::{tokio::{connect_async, ConnectStream}, tungstenite::Message, WebSocketStream};
+use futures::{SinkExt, StreamExt};
+use serde::{Serialize, Deserialize};
+use serde_json::json;
+use tokio::{sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender}, time::{delay_until, Instant}};
+
+use crate::TwitchClient;
+use super::{PubSubMessage, Topic};
+
+pub const PUBSUB_URL: &str = "wss://pubsub-edge.twitch.tv";
+
+/// Twitch allows up to 50 topics per connection.
+const MAX_TOPICS: usize = 50;
+
+type Socket = WebSocketStream<ConnectStream>;
+
+#[derive(Debug)]
+pub enum PubSubEvent {
+    Message(Box<PubSubMessage>),
+    /// The answer to a `listen` or `unlisten` call with the same nonce.
+    Response {
+        nonce: String,
+        error: Option<String>
+    },
+    Connected,
+    /// The connection dropped, it is reconnected and its topics listened to again after `retry_in`.
+    Disconnected {
+        reason: Option<String>,
+        retry_in: Duration
+    }
+}
+
+#[derive(Clone)]
+pub struct PubSubConfig {
+    url: String,
+    ping_interval: Duration,
+    pong_timeout: Duration,
+    backoff: (Duration, Duration)
+}
+
+impl Default for PubSubConfig {
+    fn default() -> Self {
+        Self {
+            url: String::from(PUBSUB_URL),
+            ping_interval: Duration::from_secs(270),
+            pong_timeout: Duration::from_secs(10),
+            backoff: (Duration::from_secs(1), Duration::from_secs(120))
+        }
+    }
+}
+
+impl PubSubConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_url<S: Into<String>>(mut self, url: S) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Twitch requires a ping at least every 5 minutes, up to 10% jitter is added.
+    pub fn with_ping_interval(mut self, ping_interval: Duration) -> Self {
+        self.ping_interval = ping_interval;
+        self
+    }
+
+    pub fn with_pong_timeout(mut self, pong_timeout: Duration) -> Self {
+        self.pong_timeout = pong_timeout;
+        self
+    }
+
+    /// Reconnect delay, doubled after every failed attempt up to `max` with up to a second of jitter.
+    pub fn with_reconnect_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.backoff = (initial, max.max(initial));
+        self
+    }
+}
+
+enum Command {
+    Listen(String, Vec<String>),
+    Unlisten(String, Vec<String>)
+}
+
+#[derive(Deserialize)]
+struct Frame {
+    #[serde(rename = "type")]
+    kind: String,
+    nonce: Option<String>,
+    error: Option<String>,
+    data: Option<MessageData>
+}
+
+#[derive(Deserialize)]
+struct MessageData {
+    topic: String,
+    message: String
+}
+
+#[derive(Serialize)]
+struct ListenData<'a> {
+    topics: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auth_token: Option<&'a str>
+}
+
+/// A PubSub connection running on its own task.
+pub struct PubSubClient {
+    token: Arc<Mutex<Option<String>>>,
+    topics: Mutex<HashSet<String>>,
+    commands: UnboundedSender<Command>,
+    events: UnboundedReceiver<PubSubEvent>
+}
+
+impl PubSubClient {
+    /// Uses the access token of the client's auth provider, which must be a user token with the topics' scopes.
+    pub async fn connect(client: &TwitchClient, config: PubSubConfig) -> Result<Self, Box<dyn Error>> {
+        let token = client.access_token().await?;
+        Self::connect_with_token(Some(token), config).await
+    }
+
+    /// Connects with a token directly, or none for topics that do not need one.
+    pub async fn connect_with_token(token: Option<String>, config: PubSubConfig) -> Result<Self, Box<dyn Error>> {
+        let (socket, _) = connect_async(config.url.as_str()).await?;
+        let (commands, commands_receiver) = unbounded_channel();
+        let (events_sender, events) = unbounded_channel();
+        let token = Arc::new(Mutex::new(token));
+
+        tokio::spawn(run(socket, config, Arc::clone(&token), commands_receiver, events_sender));
+
+        Ok(Self {
+            token,
+            topics: Mutex::new(HashSet::new()),
+            commands,
+            events
+        })
+    }
+
+    /// Used by every following `listen` and reconnect.
+    pub fn update_token<S: Into<String>>(&self, token: S) {
+        *self.token.lock().unwrap() = Some(token.into());
+    }
+
+    /// Returns the nonce of the `PubSubEvent::Response` that confirms the topics.
+    pub fn listen(&self, topics: Vec<Topic>) -> Result<String, Box<dyn Error>> {
+        let topics: Vec<String> = topics.iter().map(|x| x.to_string()).collect();
+
+        {
+            let mut current = self.topics.lock().unwrap();
+            if current.iter().chain(topics.iter()).collect::<HashSet<_>>().len() > MAX_TOPICS {
+                return Err(String::from("Cannot listen to more than 50 topics per connection!").into());
+            }
+            current.extend(topics.iter().cloned());
+        }
+
+        let nonce = nonce();
+        self.commands.send(Command::Listen(nonce.clone(), topics))
+            .map_err(|_| String::from("PubSub connection is closed!"))?;
+        Ok(nonce)
+    }
+
+    pub fn unlisten(&self, topics: Vec<Topic>) -> Result<String, Box<dyn Error>> {
+        let topics: Vec<String> = topics.iter().map(|x| x.to_string()).collect();
+
+        {
+            let mut current = self.topics.lock().unwrap();
+            for topic in &topics {
+                current.remove(topic);
+            }
+        }
+
+        let nonce = nonce();
+        self.commands.send(Command::Unlisten(nonce.clone(), topics))
+            .map_err(|_| String::from("PubSub connection is closed!"))?;
+        Ok(nonce)
+    }
+
+    pub async fn next_event(&mut self) -> Option<PubSubEvent> {
+        self.events.recv().await
+    }
+}
+
+fn nonce() -> String {
+    format!("{:x}", SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |x| x.as_nanos()))
+}
+
+/// Up to `max` of pseudo random delay, so clients do not reconnect in lockstep.
+fn jitter(max: Duration) -> Duration {
+    let seed = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |x| x.subsec_nanos());
+    max.mul_f64(f64::from(seed % 1000) / 1000.0)
+}
+
+async fn send(socket: &mut Socket, kind: &str, nonce: &str, topics: &[String], token: Option<&str>) -> Result<(), String> {
+    let frame = json!({
+        "type": kind,
+        "nonce": nonce,
+        "data": ListenData {
+            topics,
+            auth_token: token
+        }
+    });
+
+    socket.send(Message::Text(frame.to_string())).await.map_err(|x| x.to_string())
+}
+
+enum Step {
+    Frame(Option<Result<Message, String>>),
+    Command(Option<Command>),
+    Timer
+}
+
+async fn run(socket: Socket, config: PubSubConfig, token: Arc<Mutex<Option<String>>>, mut commands: UnboundedReceiver<Command>, events: UnboundedSender<PubSubEvent>) {
+    let mut socket = Some(socket);
+    let mut topics: HashSet<String> = HashSet::new();
+    let mut backoff = config.backoff.0;
+
+    loop {
+        let connected = match socket.take() {
+            Some(socket) => Ok(socket),
+            None => connect_async(config.url.as_str()).await.map(|x| x.0).map_err(|x| x.to_string())
+        };
+
+        let reason = match connected {
+            Ok(mut socket) => {
+                backoff = config.backoff.0;
+                let _ = events.send(PubSubEvent::Connected);
+                session(&mut socket, &config, &token, &mut topics, &mut commands, &events).await
+            },
+            Err(error) => Err(error)
+        };
+
+        let reason = match reason {
+            Ok(true) => None,
+            Ok(false) => return,
+            Err(error) => Some(error)
+        };
+
+        let retry_in = backoff + jitter(Duration::from_secs(1));
+        if events.send(PubSubEvent::Disconnected { reason, retry_in }).is_err() {
+            return;
+        }
+
+        let delay = tokio::time::delay_for(retry_in);
+        tokio::pin!(delay);
+        loop {
+            tokio::select! {
+                _ = &mut delay => break,
+                command = commands.recv() => match command {
+                    Some(Command::Listen(_, listen)) => topics.extend(listen),
+                    Some(Command::Unlisten(_, unlisten)) => {
+                        for topic in unlisten {
+                            topics.remove(&topic);
+                        }
+                    },
+                    None => return
+                }
+            }
+        }
+
+        backoff = (backoff * 2).min(config.backoff.1);
+    }
+}
+
+/// Serves one connection, returns whether to reconnect.
+async fn session(socket: &mut Socket, config: &PubSubConfig, token: &Mutex<Option<String>>, topics: &mut HashSet<String>, commands: &mut UnboundedReceiver<Command>, events: &UnboundedSender<PubSubEvent>) -> Result<bool, String> {
+    if !topics.is_empty() {
+        let current = token.lock().unwrap().clone();
+        let listen: Vec<String> = topics.iter().cloned().collect();
+        send(socket, "LISTEN", &nonce(), &listen, current.as_deref()).await?;
+    }
+
+    let mut next_ping = Instant::now() + config.ping_interval + jitter(config.ping_interval / 10);
+    let mut pong_deadline = None;
+
+    loop {
+        let step = tokio::select! {
+            frame = socket.next() => Step::Frame(frame.map(|x| x.map_err(|x| x.to_string()))),
+            command = commands.recv() => Step::Command(command),
+            _ = delay_until(pong_deadline.unwrap_or(next_ping)) => Step::Timer
+        };
+
+        match step {
+            Step::Frame(Some(Ok(Message::Text(text)))) => {
+                let frame: Frame = match serde_json::from_str(&text) {
+                    Ok(frame) => frame,
+                    Err(_) => continue
+                };
+
+                let event = match frame.kind.as_str() {
+                    "PONG" => {
+                        pong_deadline = None;
+                        continue;
+                    },
+                    "RECONNECT" => return Err(String::from("Server requested a reconnect")),
+                    "RESPONSE" => PubSubEvent::Response {
+                        nonce: frame.nonce.unwrap_or_default(),
+                        error: frame.error.filter(|x| !x.is_empty())
+                    },
+                    "MESSAGE" => match frame.data {
+                        Some(data) => PubSubEvent::Message(Box::new(PubSubMessage::decode(&data.topic, &data.message))),
+                        None => continue
+                    },
+                    _ => continue
+                };
+
+                if events.send(event).is_err() {
+                    return Ok(false);
+                }
+            },
+            Step::Frame(Some(Ok(Message::Close(frame)))) => return Err(frame.map_or_else(|| String::from("Connection closed"), |x| x.reason.to_string())),
+            Step::Frame(Some(Ok(_))) => {},
+            Step::Frame(Some(Err(error))) => return Err(error),
+            Step::Frame(None) => return Err(String::from("Connection closed")),
+            Step::Command(Some(command)) => {
+                let current = token.lock().unwrap().clone();
+                // Tracked before sending, a failed send reconnects with what the user asked for.
+                match command {
+                    Command::Listen(nonce, listen) => {
+                        topics.extend(listen.iter().cloned());
+                        send(socket, "LISTEN", &nonce, &listen, current.as_deref()).await?;
+                    },
+                    Command::Unlisten(nonce, unlisten) => {
+                        for topic in &unlisten {
+                            topics.remove(topic);
+                        }
+                        send(socket, "UNLISTEN", &nonce, &unlisten, current.as_deref()).await?;
+                    }
+                }
+            },
+            Step::Command(None) => return Ok(false),
+            Step::Timer => {
+                if pong_deadline.is_some() {
+                    return Err(String::from("No PONG received in time"));
+                }
+
+                socket.send(Message::Text(String::from(r#"{"type":"PING"}"#))).await.map_err(|x| x.to_string())?;
+                pong_deadline = Some(Instant::now() + config.pong_timeout);
+                next_ping = Instant::now() + config.ping_interval + jitter(config.ping_interval / 10);
+            }
+        }
+    }
+}
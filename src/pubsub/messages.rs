@@ -0,0 +1,318 @@
+
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+
+use crate::api::helix::id::{BroadcasterId, UserId};
+use crate::api::helix::time::{Timestamp, deserialize_optional_timestamp};
+use super::Topic;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModerationAction {
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    /// Such as `ban`, `timeout`, `delete` or `slow`.
+    pub moderation_action: String,
+    #[serde(default)]
+    pub args: Option<Vec<String>>,
+    pub created_by: String,
+    pub created_by_user_id: UserId,
+    #[serde(default)]
+    pub target_user_id: Option<UserId>,
+    #[serde(default)]
+    pub target_user_login: Option<String>,
+    #[serde(default)]
+    pub msg_id: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub created_at: Option<Timestamp>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PubSubUser {
+    pub id: UserId,
+    pub login: String,
+    pub display_name: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RedemptionReward {
+    pub id: String,
+    pub channel_id: BroadcasterId,
+    pub title: String,
+    pub prompt: String,
+    pub cost: u64,
+    #[serde(default)]
+    pub is_user_input_required: bool,
+    #[serde(default)]
+    pub is_sub_only: bool,
+    #[serde(default)]
+    pub background_color: Option<String>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChannelPointsRedemption {
+    pub id: String,
+    pub user: PubSubUser,
+    pub channel_id: BroadcasterId,
+    pub redeemed_at: Timestamp,
+    pub reward: RedemptionReward,
+    #[serde(default)]
+    pub user_input: Option<String>,
+    pub status: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BitsEventData {
+    /// Unset for anonymous cheers.
+    pub user_name: Option<String>,
+    pub user_id: Option<UserId>,
+    pub channel_name: String,
+    pub channel_id: BroadcasterId,
+    pub time: Timestamp,
+    pub chat_message: String,
+    pub bits_used: u64,
+    pub total_bits_used: u64,
+    pub context: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BitsEvent {
+    pub data: BitsEventData,
+    pub version: String,
+    pub message_type: String,
+    pub message_id: String,
+    #[serde(default)]
+    pub is_anonymous: bool,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WhisperBadge {
+    pub id: String,
+    pub version: String,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WhisperTags {
+    pub login: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub badges: Vec<WhisperBadge>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WhisperRecipient {
+    pub id: u64,
+    pub username: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub color: Option<String>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Whisper {
+    pub id: u64,
+    pub message_id: String,
+    pub thread_id: String,
+    pub body: String,
+    pub sent_ts: u64,
+    pub from_id: u64,
+    pub tags: WhisperTags,
+    pub recipient: WhisperRecipient,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>
+}
+
+/// A decoded topic message, anything unrecognized is kept as JSON.
+#[derive(Debug)]
+pub enum PubSubPayload {
+    ModerationAction(Box<ModerationAction>),
+    ChannelPointsRedemption(Box<ChannelPointsRedemption>),
+    Bits(Box<BitsEvent>),
+    Whisper(Box<Whisper>),
+    Unknown(Value)
+}
+
+#[derive(Debug)]
+pub struct PubSubMessage {
+    pub topic: Topic,
+    pub payload: PubSubPayload
+}
+
+#[derive(Deserialize)]
+struct Typed {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    data: Value,
+    #[serde(default)]
+    data_object: Value
+}
+
+#[derive(Deserialize)]
+struct RedemptionData {
+    redemption: ChannelPointsRedemption
+}
+
+impl PubSubMessage {
+    /// Decodes the message string of a `MESSAGE` frame, which is itself JSON.
+    pub fn decode(topic: &str, message: &str) -> Self {
+        let topic = Topic::parse(topic);
+        let value = serde_json::from_str(message).unwrap_or_else(|_| Value::String(message.to_owned()));
+
+        Self {
+            payload: decode_payload(&topic, &value).unwrap_or(PubSubPayload::Unknown(value)),
+            topic
+        }
+    }
+}
+
+fn decode_payload(topic: &Topic, value: &Value) -> Option<PubSubPayload> {
+    let typed = || serde_json::from_value::<Typed>(value.clone()).ok();
+
+    match topic {
+        Topic::ChatModeratorActions { .. } => {
+            let typed = typed()?;
+            if typed.kind != "moderation_action" {
+                return None;
+            }
+
+            serde_json::from_value(typed.data).ok().map(|x| PubSubPayload::ModerationAction(Box::new(x)))
+        },
+        Topic::ChannelPoints { .. } => {
+            let typed = typed()?;
+            if typed.kind != "reward-redeemed" {
+                return None;
+            }
+
+            serde_json::from_value::<RedemptionData>(typed.data).ok()
+                .map(|x| PubSubPayload::ChannelPointsRedemption(Box::new(x.redemption)))
+        },
+        Topic::Bits { .. } => serde_json::from_value(value.clone()).ok().map(|x| PubSubPayload::Bits(Box::new(x))),
+        Topic::Whispers { .. } => {
+            let typed = typed()?;
+            if typed.kind != "whisper_received" && typed.kind != "whisper_sent" {
+                return None;
+            }
+
+            serde_json::from_value(typed.data_object).ok().map(|x| PubSubPayload::Whisper(Box::new(x)))
+        },
+        Topic::Other(_) => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn redemption() {
+        let message = json!({
+            "type": "reward-redeemed",
+            "data": {
+                "timestamp": "2019-11-12T01:29:34.98329743Z",
+                "redemption": {
+                    "id": "9203c6f0-51b6-4d1d-a9ae-8eafdb0d6d47",
+                    "user": { "id": "30515034", "login": "davethecust", "display_name": "davethecust" },
+                    "channel_id": "30515034",
+                    "redeemed_at": "2019-12-11T18:52:53.128421623Z",
+                    "reward": {
+                        "id": "6ef17bb2-e5ae-432e-8b3f-5ac4dd774668",
+                        "channel_id": "30515034",
+                        "title": "hit a gleesh walk on stream",
+                        "prompt": "cleanside's finest",
+                        "cost": 10,
+                        "is_user_input_required": true,
+                        "is_sub_only": false,
+                        "max_per_stream": { "is_enabled": false, "max_per_stream": 0 }
+                    },
+                    "user_input": "yeooo",
+                    "status": "FULFILLED"
+                }
+            }
+        });
+
+        let message = PubSubMessage::decode("channel-points-channel-v1.30515034", &message.to_string());
+        match message.payload {
+            PubSubPayload::ChannelPointsRedemption(redemption) => {
+                assert_eq!(redemption.user.id.as_str(), "30515034");
+                assert_eq!(redemption.channel_id.as_str(), "30515034");
+                assert_eq!(redemption.redeemed_at.as_str(), "2019-12-11T18:52:53.128421623Z");
+                assert!(redemption.reward.extra.contains_key("max_per_stream"));
+            },
+            payload => panic!("unexpected payload {:?}", payload)
+        }
+    }
+
+    #[test]
+    fn anonymous_bits() {
+        let message = json!({
+            "data": {
+                "user_name": null,
+                "user_id": null,
+                "channel_name": "lirik",
+                "channel_id": "23161357",
+                "time": "2017-02-09T13:23:58.168Z",
+                "chat_message": "cheer10000 New badge hype!",
+                "bits_used": 10000,
+                "total_bits_used": 25000,
+                "context": "cheer",
+                "badge_entitlement": null
+            },
+            "version": "1.0",
+            "message_type": "bits_event",
+            "message_id": "8145728a4-35f0-4cf7-9dc0-f2ef24de1eb6",
+            "is_anonymous": true
+        });
+
+        let message = PubSubMessage::decode("channel-bits-events-v2.23161357", &message.to_string());
+        match message.payload {
+            PubSubPayload::Bits(bits) => {
+                assert_eq!(bits.data.user_id, None);
+                assert_eq!(bits.data.channel_id.as_str(), "23161357");
+                assert!(bits.data.extra.contains_key("badge_entitlement"));
+                assert!(bits.is_anonymous);
+            },
+            payload => panic!("unexpected payload {:?}", payload)
+        }
+    }
+
+    #[test]
+    fn unknown() {
+        let message = PubSubMessage::decode("channel-points-channel-v1.30515034", r#"{"type":"custom-reward-updated","data":{}}"#);
+        assert!(matches!(message.payload, PubSubPayload::Unknown(_)));
+
+        let message = PubSubMessage::decode("something-new.1", "not json");
+        assert!(matches!(message.payload, PubSubPayload::Unknown(Value::String(_))));
+    }
+}
@@ -0,0 +1,9 @@
+
+mod topic;
+pub use topic::*;
+
+mod messages;
+pub use messages::*;
+
+mod client;
+pub use client::*;
@@ -0,0 +1,56 @@
+
+use std::fmt;
+use crate::api::helix::id::{BroadcasterId, UserId};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Topic {
+    /// Moderation actions in `channel_id`, seen as the moderator `user_id`.
+    ChatModeratorActions {
+        user_id: UserId,
+        channel_id: BroadcasterId
+    },
+    ChannelPoints {
+        channel_id: BroadcasterId
+    },
+    Bits {
+        channel_id: BroadcasterId
+    },
+    Whispers {
+        user_id: UserId
+    },
+    Other(String)
+}
+
+impl Topic {
+    pub fn parse(topic: &str) -> Self {
+        let mut parts = topic.split('.');
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some("chat_moderator_actions"), Some(user_id), Some(channel_id), None) => Topic::ChatModeratorActions {
+                user_id: user_id.into(),
+                channel_id: channel_id.into()
+            },
+            (Some("channel-points-channel-v1"), Some(channel_id), None, None) => Topic::ChannelPoints {
+                channel_id: channel_id.into()
+            },
+            (Some("channel-bits-events-v2"), Some(channel_id), None, None) => Topic::Bits {
+                channel_id: channel_id.into()
+            },
+            (Some("whispers"), Some(user_id), None, None) => Topic::Whispers {
+                user_id: user_id.into()
+            },
+            _ => Topic::Other(topic.to_owned())
+        }
+    }
+}
+
+impl fmt::Display for Topic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Topic::ChatModeratorActions { user_id, channel_id } => write!(f, "chat_moderator_actions.{}.{}", user_id, channel_id),
+            Topic::ChannelPoints { channel_id } => write!(f, "channel-points-channel-v1.{}", channel_id),
+            Topic::Bits { channel_id } => write!(f, "channel-bits-events-v2.{}", channel_id),
+            Topic::Whispers { user_id } => write!(f, "whispers.{}", user_id),
+            Topic::Other(topic) => write!(f, "{}", topic)
+        }
+    }
+}
@@ -1,14 +1,135 @@
 use std::error::Error;
+#[cfg(any(feature = "whispers", feature = "tmi"))]
+use std::{collections::VecDeque, sync::Mutex, time::Duration};
+#[cfg(any(feature = "whispers", feature = "tmi"))]
+use tokio::time::{delay_until, Instant};
 
+#[allow(dead_code)]
 pub fn extend_url<S: Into<String>, T: serde::Serialize>(url: S, name: S, value: &Option<Vec<T>>) -> Result<String, Box<dyn Error>> {
     let mut url = url.into();
     if let Some(ref value) = value {
         let name = name.into();
-        let extension = serde_urlencoded::to_string(&value.iter().map(|x| (&name, x)).collect::<Vec<(&String, &T)>>())?;
+        let extension = serde_urlencoded::to_string(value.iter().map(|x| (&name, x)).collect::<Vec<(&String, &T)>>())?;
         if !extension.is_empty() {
-            url.push_str("?");
+            url.push('?');
             url.push_str(&extension);
         }
     };
     Ok(url)
-}
\ No newline at end of file
+}
+
+/// Sliding window rate limiter, `acquire` waits until a slot is free in every window.
+#[cfg(any(feature = "whispers", feature = "tmi"))]
+pub(crate) struct RateLimiter {
+    limits: Vec<(usize, Duration)>,
+    history: Mutex<VecDeque<Instant>>
+}
+
+#[cfg(any(feature = "whispers", feature = "tmi"))]
+impl RateLimiter {
+    pub(crate) fn new(limits: Vec<(usize, Duration)>) -> Self {
+        Self {
+            limits,
+            history: Mutex::new(VecDeque::new())
+        }
+    }
+
+    pub(crate) async fn acquire(&self) {
+        loop {
+            let wait_until = {
+                let mut history = self.history.lock().unwrap();
+                let now = Instant::now();
+                let longest = self.limits.iter().map(|x| x.1).max().unwrap_or_default();
+
+                while history.front().is_some_and(|x| now.duration_since(*x) >= longest) {
+                    history.pop_front();
+                }
+
+                let wait_until = self.limits.iter()
+                    .filter_map(|(count, window)| {
+                        let used = history.iter().filter(|x| now.duration_since(**x) < *window).count();
+                        if used < *count {
+                            None
+                        } else {
+                            history.iter().rev().nth(count - 1).map(|x| *x + *window)
+                        }
+                    })
+                    .max();
+
+                if wait_until.is_none() {
+                    history.push_back(now);
+                }
+
+                wait_until
+            };
+
+            match wait_until {
+                Some(wait_until) => delay_until(wait_until).await,
+                None => return
+            }
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "whispers", feature = "tmi")))]
+mod tests {
+    use std::time::Duration;
+    use tokio::time::{self, Instant};
+    use super::RateLimiter;
+
+    /// Scaled down whisper limits, 3 per 60ms and 5 per 300ms instead of 3/s and 100/min.
+    fn limiter() -> RateLimiter {
+        RateLimiter::new(vec![(3, Duration::from_millis(60)), (5, Duration::from_millis(300))])
+    }
+
+    /// The clock is paused, so this only allows for the timer rounding up to its next tick.
+    fn assert_elapsed(start: Instant, millis: u64) {
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(millis) && elapsed < Duration::from_millis(millis + 5), "{:?}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn short_window() {
+        time::pause();
+        let limiter = limiter();
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        assert_elapsed(start, 0);
+
+        limiter.acquire().await;
+        assert_elapsed(start, 60);
+    }
+
+    #[tokio::test]
+    async fn long_window() {
+        time::pause();
+        let limiter = limiter();
+        let start = Instant::now();
+
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        assert_elapsed(start, 60);
+
+        limiter.acquire().await;
+        assert_elapsed(start, 300);
+    }
+
+    #[tokio::test]
+    async fn frees_slots_after_window() {
+        time::pause();
+        let limiter = limiter();
+
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        time::advance(Duration::from_millis(60)).await;
+
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert_elapsed(start, 0);
+    }
+}